@@ -0,0 +1,388 @@
+pub mod unicode_block_hebrew {
+    use crate::acronyms::{acronym_letters, find_acronyms, split_prefixes, STARTER_ACRONYMS};
+    use std::borrow::Cow;
+    use std::collections::HashMap;
+    use std::ops::Range;
+
+    /// The way a shortened Hebrew word is marked.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum AcronymKind {
+        /// Rashei tevot, marked with a gershayim before the last letter, as in רש״י or תנ״ך
+        Acronym,
+        /// An abbreviation, marked with a geresh after the last letter, as in וכו׳
+        Abbreviation,
+    }
+
+    /// An acronym or abbreviation found by [`find_hbr_acronyms`].
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct HebrewAcronym<'a> {
+        /// The byte offsets of the acronym in the given string
+        pub span: Range<usize>,
+        /// The acronym as written
+        pub text: &'a str,
+        pub kind: AcronymKind,
+        /// The candidate expansions from the dictionary, empty when the dictionary does not know the acronym
+        pub expansions: Vec<String>,
+    }
+
+    /// A source of expansions for Hebrew acronyms and abbreviations.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{find_hbr_acronyms, AcronymDictionary};
+    ///
+    /// struct Military;
+    ///
+    /// impl AcronymDictionary for Military {
+    ///     fn expansions(&self, acronym: &str) -> Vec<String> {
+    ///         match acronym {
+    ///             "רמטכ״ל" => vec!["ראש המטה הכללי".to_string()],
+    ///             _ => Vec::new(),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let found = find_hbr_acronyms("הרמטכ\"ל", &Military);
+    /// assert_eq!(found[0].expansions, vec!["הראש המטה הכללי"]);
+    /// ```
+    pub trait AcronymDictionary {
+        /// Returns the expansions of the given acronym or abbreviation, most common first.
+        ///
+        /// The acronym is given without points, with a gershayim (U+05F4) or geresh (U+05F3).
+        fn expansions(&self, acronym: &str) -> Vec<String>;
+    }
+
+    impl AcronymDictionary for HashMap<String, Vec<String>> {
+        fn expansions(&self, acronym: &str) -> Vec<String> {
+            self.get(acronym).cloned().unwrap_or_default()
+        }
+    }
+
+    /// A bundled dictionary of common rabbinic and modern Hebrew acronyms and abbreviations.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct StarterAcronymDictionary;
+
+    impl AcronymDictionary for StarterAcronymDictionary {
+        fn expansions(&self, acronym: &str) -> Vec<String> {
+            STARTER_ACRONYMS
+                .iter()
+                .find(|(key, _)| *key == acronym)
+                .map(|(_, expansions)| expansions.iter().map(|e| e.to_string()).collect())
+                .unwrap_or_default()
+        }
+    }
+
+    /// Finds the acronyms marked with a gershayim and the abbreviations marked with a geresh in the given string,
+    /// and looks up their expansions in the dictionary.
+    ///
+    /// A quotation mark or apostrophe typed in place of a gershayim or geresh is recognized as well,
+    /// see [`crate::normalize_hbr_punctuation`].
+    /// When the dictionary does not know an acronym, up to three prefix letters (ו, ה, ב, כ, ל, מ, ש) are split off
+    /// and the expansions start with that prefix. Gematria numerals such as כ״ה are found without expansions.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{find_hbr_acronyms, AcronymKind, StarterAcronymDictionary};
+    ///
+    /// let found = find_hbr_acronyms("פירוש רש״י על התנ״ך וכו׳", &StarterAcronymDictionary);
+    /// assert_eq!(found.len(), 3);
+    /// assert_eq!(found[0].text, "רש״י");
+    /// assert_eq!(found[0].expansions, vec!["רבי שלמה יצחקי"]);
+    /// assert_eq!(found[1].span, 25..35);
+    /// assert_eq!(found[1].expansions, vec!["התורה נביאים כתובים"]);
+    /// assert_eq!(found[2].kind, AcronymKind::Abbreviation);
+    /// assert_eq!(found[2].expansions, vec!["וכולי"]);
+    /// ```
+    pub fn find_hbr_acronyms<'a, D>(text: &'a str, dictionary: &D) -> Vec<HebrewAcronym<'a>>
+    where
+        D: AcronymDictionary + ?Sized,
+    {
+        find_acronyms(text)
+            .into_iter()
+            .map(|(span, key, kind)| {
+                let mut expansions = dictionary.expansions(&key);
+                if expansions.is_empty() {
+                    for (prefix, rest) in split_prefixes(&key) {
+                        expansions = dictionary
+                            .expansions(rest)
+                            .into_iter()
+                            .map(|expansion| format!("{}{}", prefix, expansion))
+                            .collect();
+                        if !expansions.is_empty() {
+                            break;
+                        }
+                    }
+                }
+                HebrewAcronym {
+                    text: &text[span.clone()],
+                    span,
+                    kind,
+                    expansions,
+                }
+            })
+            .collect()
+    }
+
+    /// Replaces every acronym and abbreviation known to the dictionary by its first expansion,
+    /// e.g. to index text for search.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{expand_hbr_acronyms, StarterAcronymDictionary};
+    ///
+    /// let expanded = expand_hbr_acronyms("לימוד תנ\"ך בצה\"ל", &StarterAcronymDictionary);
+    /// assert_eq!(expanded, "לימוד תורה נביאים כתובים בצבא הגנה לישראל");
+    /// ```
+    pub fn expand_hbr_acronyms<'a, D>(text: &'a str, dictionary: &D) -> Cow<'a, str>
+    where
+        D: AcronymDictionary + ?Sized,
+    {
+        let found = find_hbr_acronyms(text, dictionary);
+        if found.iter().all(|acronym| acronym.expansions.is_empty()) {
+            return Cow::Borrowed(text);
+        }
+        let mut expanded = String::with_capacity(text.len() * 2);
+        let mut last = 0;
+        for acronym in found {
+            if let Some(expansion) = acronym.expansions.first() {
+                expanded.push_str(&text[last..acronym.span.start]);
+                expanded.push_str(expansion);
+                last = acronym.span.end;
+            }
+        }
+        expanded.push_str(&text[last..]);
+        Cow::Owned(expanded)
+    }
+
+    /// Checks if a phrase can be shortened to the given acronym or abbreviation,
+    /// without a dictionary.
+    ///
+    /// Every word of the phrase has to give the start of its letters, one letter or more, in order,
+    /// where a word may leave out its conjunction ו; points are ignored and final letters count as normal letters.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::hbr_acronym_matches;
+    ///
+    /// assert!(hbr_acronym_matches("תנ״ך", "תורה נביאים כתובים"));
+    /// assert!(hbr_acronym_matches("בי\"ס", "בֵּית סֵפֶר"));
+    /// assert!(hbr_acronym_matches("וכו׳", "וכולי"));
+    /// assert!(!hbr_acronym_matches("תנ״ך", "תורה ונביאים"));
+    /// ```
+    pub fn hbr_acronym_matches(acronym: &str, phrase: &str) -> bool {
+        let letters = acronym_letters(acronym);
+        let words: Vec<Vec<char>> = phrase
+            .split(|c: char| c.is_whitespace() || c == '\u{05BE}' || c == '-')
+            .map(acronym_letters)
+            .filter(|word| !word.is_empty())
+            .collect();
+        fn fits(letters: &[char], words: &[Vec<char>]) -> bool {
+            let (word, rest) = match words.split_first() {
+                Some(split) => split,
+                None => return letters.is_empty(),
+            };
+            let starts: &[usize] = if word.len() > 1 && word[0] == 'ו' {
+                &[0, 1]
+            } else {
+                &[0]
+            };
+            starts.iter().any(|&start| {
+                let word = &word[start..];
+                (1..=word.len().min(letters.len()))
+                    .any(|n| letters[..n] == word[..n] && fits(&letters[n..], rest))
+            })
+        }
+        !letters.is_empty() && fits(&letters, &words)
+    }
+}
+
+use crate::clusters::is_hbr_combining;
+use crate::final_forms::to_normal_form;
+use crate::punctuation::{hebrew_punctuation, is_letter};
+use std::ops::Range;
+use unicode_block_hebrew::AcronymKind;
+
+/// The prefix letters that can be written before an acronym.
+const PREFIXES: &str = "ובכלמשה";
+
+/// Common rabbinic and modern acronyms and abbreviations, with their expansions.
+pub(crate) const STARTER_ACRONYMS: &[(&str, &[&str])] = &[
+    ("רש״י", &["רבי שלמה יצחקי"]),
+    ("רמב״ם", &["רבי משה בן מימון"]),
+    ("רמב״ן", &["רבי משה בן נחמן"]),
+    ("תנ״ך", &["תורה נביאים כתובים"]),
+    ("חז״ל", &["חכמינו זכרונם לברכה"]),
+    ("ז״ל", &["זכרונו לברכה", "זכרונה לברכה"]),
+    ("זצ״ל", &["זכר צדיק לברכה"]),
+    ("ע״ה", &["עליו השלום", "עליה השלום"]),
+    ("ב״ה", &["ברוך השם", "בעזרת השם"]),
+    ("בע״ה", &["בעזרת השם"]),
+    ("שליט״א", &["שיחיה לאורך ימים טובים אמן"]),
+    ("אדמו״ר", &["אדוננו מורנו ורבנו"]),
+    ("שו״ת", &["שאלות ותשובות"]),
+    ("ר״ת", &["ראשי תיבות", "רבנו תם"]),
+    ("הנ״ל", &["הנזכר לעיל"]),
+    ("כנ״ל", &["כנזכר לעיל"]),
+    ("אא״כ", &["אלא אם כן"]),
+    ("בד״כ", &["בדרך כלל"]),
+    ("ע״י", &["על ידי"]),
+    ("עי״ז", &["על ידי זה"]),
+    ("ע״פ", &["על פי"]),
+    ("א״י", &["ארץ ישראל"]),
+    ("ארה״ב", &["ארצות הברית"]),
+    ("צה״ל", &["צבא הגנה לישראל"]),
+    ("ת״א", &["תל אביב"]),
+    ("עו״ד", &["עורך דין"]),
+    ("רו״ח", &["רואה חשבון"]),
+    ("ד״ר", &["דוקטור"]),
+    ("יו״ר", &["יושב ראש"]),
+    ("ח״כ", &["חבר כנסת"]),
+    ("מנכ״ל", &["מנהל כללי"]),
+    ("דו״ח", &["דין וחשבון"]),
+    ("בי״ס", &["בית ספר"]),
+    ("ביה״ס", &["בית הספר"]),
+    ("מו״ל", &["מוציא לאור"]),
+    ("מ״מ", &["ממלא מקום", "מילימטר"]),
+    ("ס״מ", &["סנטימטר"]),
+    ("ק״מ", &["קילומטר"]),
+    ("לפנה״ס", &["לפני הספירה"]),
+    ("לסה״נ", &["לספירת הנוצרים"]),
+    ("וכו׳", &["וכולי"]),
+    ("וגו׳", &["וגומר"]),
+    ("וכד׳", &["וכדומה"]),
+    ("פרופ׳", &["פרופסור"]),
+    ("גב׳", &["גברת"]),
+    ("מס׳", &["מספר"]),
+    ("עמ׳", &["עמוד"]),
+    ("רח׳", &["רחוב"]),
+    ("טל׳", &["טלפון"]),
+    ("ר׳", &["רבי"]),
+    ("ה׳", &["השם"]),
+];
+
+/// Finds the acronyms and abbreviations in a string,
+/// and returns their spans, their lookup keys (without points, with Hebrew punctuation) and their kinds.
+pub(crate) fn find_acronyms(text: &str) -> Vec<(Range<usize>, String, AcronymKind)> {
+    let replacements = hebrew_punctuation(text);
+    let punctuation_at = |pos: usize, c: char| {
+        replacements
+            .binary_search_by_key(&pos, |(span, _)| span.start)
+            .map_or(c, |i| replacements[i].1)
+    };
+    let mut found = Vec::new();
+    let mut word: Option<(usize, String)> = None;
+    let mut finish = |word: &mut Option<(usize, String)>, end: usize| {
+        if let Some((start, key)) = word.take() {
+            let letters = key.chars().filter(|&c| is_letter(c)).count();
+            let kind = match key.find('\u{05F4}') {
+                Some(pos) if pos > 0 && key[pos..].chars().skip(1).all(is_letter) => {
+                    let last = key[pos..].chars().skip(1).count();
+                    Some(AcronymKind::Acronym).filter(|_| last == 1)
+                }
+                None if key.ends_with('\u{05F3}')
+                    && letters > 0
+                    && key.matches('\u{05F3}').count() == 1 =>
+                {
+                    Some(AcronymKind::Abbreviation)
+                }
+                _ => None,
+            };
+            if let Some(kind) = kind {
+                found.push((start..end, key, kind));
+            }
+        }
+    };
+    for (pos, c) in text.char_indices() {
+        let c = punctuation_at(pos, c);
+        let in_word = is_letter(c)
+            || (word.is_some() && (is_hbr_combining(c) || c == '\u{05F3}' || c == '\u{05F4}'));
+        if in_word {
+            let (_, key) = word.get_or_insert_with(|| (pos, String::new()));
+            if !is_hbr_combining(c) {
+                key.push(c);
+            }
+        } else {
+            finish(&mut word, pos);
+        }
+    }
+    finish(&mut word, text.len());
+    found
+}
+
+/// Splits up to three prefix letters off an acronym, keeping at least two letters.
+pub(crate) fn split_prefixes(key: &str) -> Vec<(&str, &str)> {
+    let mut splits = Vec::new();
+    let mut rest = key;
+    for _ in 0..3 {
+        let first = match rest.chars().next() {
+            Some(first) if PREFIXES.contains(first) => first,
+            _ => break,
+        };
+        rest = &rest[first.len_utf8()..];
+        if rest.chars().filter(|&c| is_letter(c)).count() < 2 {
+            break;
+        }
+        splits.push((&key[..key.len() - rest.len()], rest));
+    }
+    splits
+}
+
+/// Returns the letters of a word or acronym, with final letters folded.
+pub(crate) fn acronym_letters(text: &str) -> Vec<char> {
+    text.chars()
+        .filter(|&c| is_letter(c))
+        .map(to_normal_form)
+        .collect()
+}
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn acronyms_detected() {
+        let text = "ז\"ל, ג׳ירפה, כ״ה באייר, \"שלום\" פרופ' כהן";
+        let found = find_hbr_acronyms(text, &StarterAcronymDictionary);
+        let texts: Vec<&str> = found.iter().map(|a| a.text).collect();
+        assert_eq!(texts, vec!["ז\"ל", "כ״ה", "פרופ'"]);
+        assert_eq!(found[0].expansions, vec!["זכרונו לברכה", "זכרונה לברכה"]);
+        assert!(found[1].expansions.is_empty());
+        assert_eq!(found[2].kind, AcronymKind::Abbreviation);
+        assert_eq!(found[2].expansions, vec!["פרופסור"]);
+    }
+
+    #[test]
+    fn acronyms_pointed_and_prefixed() {
+        let found = find_hbr_acronyms("וּכְשֶׁרַשִׁ״י", &StarterAcronymDictionary);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].span, 0..found[0].text.len());
+        assert_eq!(found[0].expansions, vec!["וכשרבי שלמה יצחקי"]);
+        let found = find_hbr_acronyms("ה׳", &StarterAcronymDictionary);
+        assert_eq!(found[0].expansions, vec!["השם"]);
+    }
+
+    #[test]
+    fn acronyms_custom_dictionary() {
+        let mut dictionary: HashMap<String, Vec<String>> = HashMap::new();
+        dictionary.insert("תנ״ך".to_string(), vec!["התנך".to_string()]);
+        let expanded = expand_hbr_acronyms("תנ״ך ורש״י", &dictionary);
+        assert_eq!(expanded, "התנך ורש״י");
+        let dictionary: &dyn AcronymDictionary = &StarterAcronymDictionary;
+        assert_eq!(
+            find_hbr_acronyms("שו\"ת", dictionary)[0].expansions.len(),
+            1
+        );
+        // units and titles are not shortened from the start of every word
+        let unmatched: Vec<&str> = crate::acronyms::STARTER_ACRONYMS
+            .iter()
+            .filter(|(acronym, expansions)| {
+                !expansions
+                    .iter()
+                    .all(|expansion| hbr_acronym_matches(acronym, expansion))
+            })
+            .map(|(acronym, _)| *acronym)
+            .collect();
+        assert_eq!(unmatched, vec!["ד״ר", "מ״מ", "ס״מ", "ק״מ"]);
+    }
+}
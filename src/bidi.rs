@@ -0,0 +1,276 @@
+pub mod unicode_block_hebrew {
+    use crate::bidi::is_bidi_control;
+    use crate::ordering::{bidi_class, BidiClass};
+    use std::borrow::Cow;
+    use std::ops::Range;
+
+    /// The way a Hebrew run is separated from the surrounding text.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum BidiIsolation {
+        /// Right-to-left isolate (U+2067) ... pop directional isolate (U+2069),
+        /// the choice for Hebrew runs when the software supports isolates
+        Isolate,
+        /// First strong isolate (U+2068) ... pop directional isolate (U+2069),
+        /// for runs whose direction is only known from their first strong character
+        FirstStrongIsolate,
+        /// A right-to-left mark (U+200F) inside the run with a left-to-right mark (U+200E) outside it
+        /// on both sides, for Hebrew runs in left-to-right text in software without isolate support
+        Marks,
+    }
+
+    /// The kind of problem found by [`check_bidi_control`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum BidiControlIssueKind {
+        /// An embedding, override or isolate that is still open at the end of the paragraph
+        Unterminated,
+        /// A pop directional formatting or isolate without a matching opening character
+        Unmatched,
+        /// An override, which shows the characters in a different order than they are stored
+        Override,
+    }
+
+    /// A problem with the bidirectional control characters of a text.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct BidiControlIssue {
+        /// The byte offsets of the control character, or of the overridden text for [`BidiControlIssueKind::Override`]
+        pub span: Range<usize>,
+        pub kind: BidiControlIssueKind,
+    }
+
+    /// Removes all bidirectional control characters from the given string.
+    ///
+    /// These are the marks (LRM, RLM, ALM), embeddings and overrides (LRE, RLE, LRO, RLO, PDF)
+    /// and isolates (LRI, RLI, FSI, PDI).
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_bidi_control;
+    ///
+    /// let test_str = "id \u{2067}שלום\u{2069}\u{200F}!";
+    /// let test_str_filtered = remove_bidi_control(test_str);
+    /// assert_eq!(test_str_filtered.as_ref(), "id שלום!");
+    /// ```
+    pub fn remove_bidi_control(string: &str) -> Cow<'_, str> {
+        if !string.chars().any(is_bidi_control) {
+            return Cow::Borrowed(string);
+        }
+        string.chars().filter(|&c| !is_bidi_control(c)).collect()
+    }
+
+    /// Wraps every Hebrew run of the given string in isolates or marks.
+    ///
+    /// A run starts and ends with a right-to-left character; spaces, punctuation and numbers
+    /// between two Hebrew words belong to the run, Latin letters and line breaks end it.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{isolate_hbr_runs, BidiIsolation};
+    ///
+    /// let test_str = "user שלום עולם logged in";
+    /// let test_str_isolated = isolate_hbr_runs(test_str, BidiIsolation::Isolate);
+    /// assert_eq!(test_str_isolated.as_ref(), "user \u{2067}שלום עולם\u{2069} logged in");
+    /// let test_str_marked = isolate_hbr_runs(test_str, BidiIsolation::Marks);
+    /// assert_eq!(
+    ///     test_str_marked.as_ref(),
+    ///     "user \u{200E}\u{200F}שלום עולם\u{200F}\u{200E} logged in"
+    /// );
+    /// ```
+    pub fn isolate_hbr_runs(string: &str, isolation: BidiIsolation) -> Cow<'_, str> {
+        let (open, close) = match isolation {
+            BidiIsolation::Isolate => ("\u{2067}", "\u{2069}"),
+            BidiIsolation::FirstStrongIsolate => ("\u{2068}", "\u{2069}"),
+            BidiIsolation::Marks => ("\u{200E}\u{200F}", "\u{200F}\u{200E}"),
+        };
+        let mut runs: Vec<Range<usize>> = Vec::new();
+        let mut current: Option<Range<usize>> = None;
+        for (pos, c) in string.char_indices() {
+            match bidi_class(c) {
+                BidiClass::R if !is_bidi_control(c) => match current.as_mut() {
+                    Some(run) => run.end = pos + c.len_utf8(),
+                    None => current = Some(pos..pos + c.len_utf8()),
+                },
+                BidiClass::L => runs.extend(current.take()),
+                _ if matches!(c, '\n' | '\r' | '\u{2029}') => runs.extend(current.take()),
+                _ => {}
+            }
+        }
+        runs.extend(current);
+        if runs.is_empty() {
+            return Cow::Borrowed(string);
+        }
+        let mut isolated =
+            String::with_capacity(string.len() + runs.len() * (open.len() + close.len()));
+        let mut copied = 0;
+        for run in runs {
+            isolated.push_str(&string[copied..run.start]);
+            isolated.push_str(open);
+            isolated.push_str(&string[run.clone()]);
+            isolated.push_str(close);
+            copied = run.end;
+        }
+        isolated.push_str(&string[copied..]);
+        Cow::Owned(isolated)
+    }
+
+    /// Checks the bidirectional control characters of the given string.
+    ///
+    /// Reports embeddings, overrides and isolates that are not closed before the end of the paragraph,
+    /// closing characters without an opening one and every override, as used by 'Trojan Source' attacks
+    /// to make code or log lines look different from what they are.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{check_bidi_control, BidiControlIssueKind};
+    ///
+    /// assert!(check_bidi_control("a \u{2067}שלום\u{2069} b").is_empty());
+    ///
+    /// let issues = check_bidi_control("if x \u{202E}} {\n");
+    /// assert_eq!(issues.len(), 2);
+    /// assert_eq!(issues[0].kind, BidiControlIssueKind::Unterminated);
+    /// assert_eq!(issues[0].span, 5..8);
+    /// assert_eq!(issues[1].kind, BidiControlIssueKind::Override);
+    /// assert_eq!(issues[1].span, 5..11);
+    /// ```
+    pub fn check_bidi_control(string: &str) -> Vec<BidiControlIssue> {
+        let mut issues = Vec::new();
+        // (opening character, byte offset) of the embeddings, overrides and isolates still open
+        let mut open: Vec<(char, usize)> = Vec::new();
+        for (pos, c) in string.char_indices() {
+            let end = pos + c.len_utf8();
+            match c {
+                '\u{202A}'..='\u{202E}' if c != '\u{202C}' => open.push((c, pos)),
+                '\u{2066}'..='\u{2068}' => open.push((c, pos)),
+                '\u{202C}' => match open.last() {
+                    Some(&(opening, start)) if !is_isolate(opening) => {
+                        open.pop();
+                        close(opening, start, end, &mut issues);
+                    }
+                    _ => issues.push(BidiControlIssue {
+                        span: pos..end,
+                        kind: BidiControlIssueKind::Unmatched,
+                    }),
+                },
+                '\u{2069}' if open.iter().any(|&(opening, _)| is_isolate(opening)) => {
+                    while let Some((opening, start)) = open.pop() {
+                        close(opening, start, end, &mut issues);
+                        if is_isolate(opening) {
+                            break;
+                        }
+                        issues.push(unterminated(string, start));
+                    }
+                }
+                '\u{2069}' => issues.push(BidiControlIssue {
+                    span: pos..end,
+                    kind: BidiControlIssueKind::Unmatched,
+                }),
+                '\n' | '\r' | '\u{0085}' | '\u{2029}' => {
+                    for (opening, start) in open.drain(..) {
+                        issues.push(unterminated(string, start));
+                        close(opening, start, pos, &mut issues);
+                    }
+                }
+                _ => {}
+            }
+        }
+        for (opening, start) in open.drain(..) {
+            issues.push(unterminated(string, start));
+            close(opening, start, string.len(), &mut issues);
+        }
+        issues.sort_by_key(|issue| (issue.span.start, issue.span.end));
+        issues
+    }
+
+    fn is_isolate(c: char) -> bool {
+        matches!(c, '\u{2066}'..='\u{2068}')
+    }
+
+    /// Reports the text covered by an override.
+    fn close(opening: char, start: usize, end: usize, issues: &mut Vec<BidiControlIssue>) {
+        if matches!(opening, '\u{202D}' | '\u{202E}') {
+            issues.push(BidiControlIssue {
+                span: start..end,
+                kind: BidiControlIssueKind::Override,
+            });
+        }
+    }
+
+    fn unterminated(string: &str, start: usize) -> BidiControlIssue {
+        let length = string[start..].chars().next().map_or(0, char::len_utf8);
+        BidiControlIssue {
+            span: start..start + length,
+            kind: BidiControlIssueKind::Unterminated,
+        }
+    }
+}
+
+/// Checks if the given character is a bidirectional mark, embedding, override or isolate.
+pub(crate) fn is_bidi_control(c: char) -> bool {
+    matches!(
+        c,
+        '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+
+    #[test]
+    fn remove_all_bidi_controls() {
+        let controls: String = "\u{061C}\u{200E}\u{200F}\u{202A}\u{202B}\u{202C}\u{202D}\u{202E}\u{2066}\u{2067}\u{2068}\u{2069}".to_string();
+        let test_str = format!("א{}ב", controls);
+        assert_eq!(remove_bidi_control(&test_str).as_ref(), "אב");
+        assert!(matches!(
+            remove_bidi_control("שלום abc"),
+            std::borrow::Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn isolate_runs_with_numbers_and_points() {
+        let test_str = "Error: קובץ 12 לא נמצא (code 3)";
+        assert_eq!(
+            isolate_hbr_runs(test_str, BidiIsolation::FirstStrongIsolate).as_ref(),
+            "Error: \u{2068}קובץ 12 לא נמצא\u{2069} (code 3)"
+        );
+        assert_eq!(
+            isolate_hbr_runs("שָׁלוֹם\nשלום", BidiIsolation::Isolate).as_ref(),
+            "\u{2067}שָׁלוֹם\u{2069}\n\u{2067}שלום\u{2069}"
+        );
+        assert_eq!(
+            isolate_hbr_runs("see ספר 2.", BidiIsolation::Marks).as_ref(),
+            "see \u{200E}\u{200F}ספר\u{200F}\u{200E} 2."
+        );
+        assert!(matches!(
+            isolate_hbr_runs("abc 123", BidiIsolation::Marks),
+            std::borrow::Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn check_balanced_controls() {
+        let test_str = "\u{202B}אב\u{202C} \u{2066}cd \u{202A}e\u{2069} \u{200F}";
+        assert_eq!(
+            check_bidi_control(test_str),
+            vec![BidiControlIssue {
+                span: 17..20,
+                kind: BidiControlIssueKind::Unterminated,
+            }]
+        );
+    }
+
+    #[test]
+    fn check_unmatched_and_overrides() {
+        let issues = check_bidi_control("a\u{202C}b\u{2069}\u{202D}c\u{202C}");
+        let kinds: Vec<_> = issues.iter().map(|issue| issue.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                BidiControlIssueKind::Unmatched,
+                BidiControlIssueKind::Unmatched,
+                BidiControlIssueKind::Override,
+            ]
+        );
+        assert_eq!(issues[2].span, 8..15);
+    }
+}
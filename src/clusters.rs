@@ -0,0 +1,107 @@
+pub mod unicode_block_hebrew {
+    use crate::clusters::is_hbr_combining;
+    use std::ops::Range;
+
+    /// A base character together with the Hebrew points, accents and marks that follow it.
+    ///
+    /// The span holds the byte offsets of the cluster within the analysed string.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct HebrewCluster<'a> {
+        pub text: &'a str,
+        pub span: Range<usize>,
+    }
+
+    impl<'a> HebrewCluster<'a> {
+        /// Returns the base character, or `None` when the cluster consists of marks only.
+        pub fn base(&self) -> Option<char> {
+            self.text.chars().next().filter(|&c| !is_hbr_combining(c))
+        }
+
+        /// Returns the combining characters that follow the base character.
+        pub fn marks(&self) -> impl Iterator<Item = char> + 'a {
+            let skip = usize::from(self.base().is_some());
+            self.text.chars().skip(skip)
+        }
+
+        /// Checks if the given combining character is attached to the base character.
+        pub fn has_mark(&self, mark: char) -> bool {
+            self.marks().any(|c| c == mark)
+        }
+    }
+
+    /// Splits the given string into clusters of a base character and its combining characters.
+    ///
+    /// Combining characters at the start of the string form a cluster without a base character.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::get_hbr_clusters;
+    ///
+    /// let clusters = get_hbr_clusters("שָׁלוֹם");
+    /// assert_eq!(clusters.len(), 4);
+    /// assert_eq!(clusters[0].text, "שָׁ");
+    /// assert_eq!(clusters[0].base(), Some('ש'));
+    /// assert_eq!(clusters[1].span, 6..8);
+    /// ```
+    pub fn get_hbr_clusters(text: &str) -> Vec<HebrewCluster<'_>> {
+        let mut clusters = Vec::new();
+        let mut start = 0;
+        for (pos, c) in text.char_indices() {
+            if pos > start && !is_hbr_combining(c) {
+                clusters.push(HebrewCluster {
+                    text: &text[start..pos],
+                    span: start..pos,
+                });
+                start = pos;
+            }
+        }
+        if start < text.len() {
+            clusters.push(HebrewCluster {
+                text: &text[start..],
+                span: start..text.len(),
+            });
+        }
+        clusters
+    }
+}
+
+use hebrew_unicode_script::HebrewUnicodeScript;
+
+/// Checks if the given character combines with the preceding base character.
+pub(crate) fn is_hbr_combining(c: char) -> bool {
+    c.is_hbr_accent() || c.is_hbr_mark() || c.is_hbr_point() || c.is_apf_point_reading_sign()
+}
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+
+    #[test]
+    fn clusters_pointed_word() {
+        let clusters = get_hbr_clusters("בְּרֵאשִׁ֖ית");
+        let texts: Vec<&str> = clusters.iter().map(|c| c.text).collect();
+        assert_eq!(texts, vec!["בְּ", "רֵ", "א", "שִׁ֖", "י", "ת"]);
+    }
+
+    #[test]
+    fn clusters_leading_mark_has_no_base() {
+        let clusters = get_hbr_clusters("ָב");
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].base(), None);
+        assert_eq!(clusters[0].marks().collect::<String>(), "ָ");
+        assert_eq!(clusters[1].base(), Some('ב'));
+    }
+
+    #[test]
+    fn clusters_mixed_text() {
+        let clusters = get_hbr_clusters("a בּ");
+        assert_eq!(clusters.len(), 3);
+        assert!(clusters[2].has_mark('\u{05BC}'));
+        assert!(!clusters[0].has_mark('\u{05BC}'));
+    }
+
+    #[test]
+    fn clusters_empty_string() {
+        assert!(get_hbr_clusters("").is_empty());
+    }
+}
@@ -0,0 +1,310 @@
+pub mod unicode_block_hebrew {
+    use crate::bidi::is_bidi_control;
+    use crate::clusters::is_hbr_combining;
+    use crate::final_forms::to_normal_form;
+    use crate::{decompose_apf, get_hbr_clusters, get_hbr_vowels, VowelKind};
+    use hebrew_unicode_script::HebrewUnicodeScript;
+    use std::cmp::Ordering;
+
+    /// The spelling by which pointed words are put in order.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum CollationSpelling {
+        /// The letters as written
+        AsWritten,
+        /// Defective spelling (ktiv haser), as in the Even-Shoshan dictionary:
+        /// vav and yod that only mark a vowel are left out
+        EvenShoshan,
+        /// Plene spelling (ktiv male) following the rules of the Academy of the Hebrew Language:
+        /// a vav is added for holam and qubuts, a yod for hiriq before a vowel and a consonantal vav is doubled
+        Academy,
+    }
+
+    /// The options for [`hbr_collation_key_with`] and [`cmp_hbr_with`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct CollationOptions {
+        pub spelling: CollationSpelling,
+    }
+
+    impl Default for CollationOptions {
+        fn default() -> Self {
+            CollationOptions {
+                spelling: CollationSpelling::AsWritten,
+            }
+        }
+    }
+
+    /// A sort key for Hebrew dictionary order, compared level by level.
+    ///
+    /// - primary: the consonants from alef to tav, final letters equal to normal ones;
+    ///   digits sort before letters and other characters after them
+    /// - secondary: the vowel of every letter in the order of the Unicode chart
+    ///   (none, shva, hataf segol, hataf patah, hataf qamats, hiriq, tsere, segol, patah, qamats, holam, qubuts, qamats qatan),
+    ///   then shin before sin and a letter without dagesh before one with dagesh
+    /// - tertiary: normal letters before final letters, then the accents, marks and punctuation
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub struct HebrewCollationKey {
+        primary: Vec<u32>,
+        secondary: Vec<u32>,
+        tertiary: Vec<u32>,
+    }
+
+    /// Builds the collation key of the given string, with the letters as written.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::hbr_collation_key;
+    ///
+    /// let mut words = vec!["תַּפּוּחַ", "מְלָכִים", "אֶרֶץ", "מֶלֶךְ", "בַּיִת"];
+    /// words.sort_by_key(|word| hbr_collation_key(word));
+    /// assert_eq!(words, vec!["אֶרֶץ", "בַּיִת", "מֶלֶךְ", "מְלָכִים", "תַּפּוּחַ"]);
+    /// ```
+    pub fn hbr_collation_key(text: &str) -> HebrewCollationKey {
+        hbr_collation_key_with(text, &CollationOptions::default())
+    }
+
+    /// Compares two strings in Hebrew dictionary order, with the letters as written.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::cmp_hbr;
+    /// use std::cmp::Ordering;
+    ///
+    /// assert_eq!(cmp_hbr("מן", "מנה"), Ordering::Less);
+    /// assert_eq!(cmp_hbr("ץ", "ת"), Ordering::Less);
+    /// assert_eq!(cmp_hbr("סֵפֶר", "סַפָּר"), Ordering::Less);
+    /// ```
+    pub fn cmp_hbr(a: &str, b: &str) -> Ordering {
+        cmp_hbr_with(a, b, &CollationOptions::default())
+    }
+
+    /// Compares two strings in Hebrew dictionary order with the given options.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{cmp_hbr_with, CollationOptions, CollationSpelling};
+    /// use std::cmp::Ordering;
+    ///
+    /// let options = CollationOptions { spelling: CollationSpelling::EvenShoshan };
+    /// assert_eq!(cmp_hbr_with("שׁוֹמֵר", "שֵׁם", &options), Ordering::Greater);
+    /// let options = CollationOptions { spelling: CollationSpelling::Academy };
+    /// assert_eq!(cmp_hbr_with("שֹׁמֵר", "שֵׁם", &options), Ordering::Less);
+    /// ```
+    pub fn cmp_hbr_with(a: &str, b: &str, options: &CollationOptions) -> Ordering {
+        hbr_collation_key_with(a, options).cmp(&hbr_collation_key_with(b, options))
+    }
+
+    /// Builds the collation key of the given string with the given options.
+    ///
+    /// The spelling options only change pointed words; presentation forms are decomposed first.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{hbr_collation_key_with, CollationOptions, CollationSpelling};
+    ///
+    /// let options = CollationOptions { spelling: CollationSpelling::EvenShoshan };
+    /// let defective = hbr_collation_key_with("שֹׁמֵר", &options);
+    /// assert_eq!(hbr_collation_key_with("שׁוֹמֵר", &options), defective);
+    /// ```
+    pub fn hbr_collation_key_with(text: &str, options: &CollationOptions) -> HebrewCollationKey {
+        let text = decompose_apf(text);
+        let clusters = get_hbr_clusters(&text);
+        let vowels = get_hbr_vowels(&text);
+        let mut key = HebrewCollationKey {
+            primary: Vec::new(),
+            secondary: Vec::new(),
+            tertiary: Vec::new(),
+        };
+        let mut ignored: Vec<u32> = Vec::new();
+        let mut next_vowel = 0;
+        for cluster in clusters.iter() {
+            let base = match cluster.base() {
+                Some(base) if !is_ignorable(base) => base,
+                _ => {
+                    ignored.extend(cluster.text.chars().map(u32::from));
+                    continue;
+                }
+            };
+            ignored.extend(
+                cluster
+                    .marks()
+                    .filter(|&c| !c.is_hbr_point())
+                    .map(u32::from),
+            );
+            let analysis = match vowels.get(next_vowel) {
+                Some(analysis) if analysis.cluster.span == cluster.span => {
+                    next_vowel += 1;
+                    analysis
+                }
+                _ => {
+                    key.push(primary_weight(base), 0, 0);
+                    continue;
+                }
+            };
+            let previous = next_vowel
+                .checked_sub(2)
+                .map(|p| &vowels[p])
+                .filter(|_| !analysis.word_start);
+            let next = vowels.get(next_vowel).filter(|next| !next.word_start);
+            let rank = cluster.marks().find_map(vowel_rank).unwrap_or(0);
+            let kind = analysis.vowel.map(|vowel| vowel.kind);
+
+            if options.spelling == CollationSpelling::EvenShoshan {
+                let mater = matches!(base, 'ו' | 'י') && analysis.is_mater_lectionis(previous);
+                let vowel_vav = base == 'ו'
+                    && previous.is_some()
+                    && matches!(kind, Some(VowelKind::HolamMale) | Some(VowelKind::Shureq));
+                if mater || vowel_vav {
+                    // the vowel of a vowel letter belongs to the preceding letter
+                    let rank = if kind == Some(VowelKind::Shureq) {
+                        QUBUTS_RANK
+                    } else {
+                        rank
+                    };
+                    if let Some(last) = key.secondary.last_mut() {
+                        if *last < 4 {
+                            *last += rank * 4;
+                        }
+                    }
+                    continue;
+                }
+            }
+
+            let sin = u32::from(cluster.has_mark('\u{05C2}')) * 2;
+            let dagesh = u32::from(cluster.has_mark('\u{05BC}'));
+            let rank = if kind == Some(VowelKind::Shureq) {
+                0
+            } else {
+                rank
+            };
+            key.push(
+                primary_weight(base),
+                rank * 4 + sin + dagesh,
+                u32::from(base.is_hbr_consonant_final()),
+            );
+
+            if options.spelling == CollationSpelling::Academy {
+                let point = analysis.vowel.map(|vowel| vowel.point);
+                let next_base = next.and_then(|next| next.cluster.base());
+                let add = match (kind, point) {
+                    (Some(VowelKind::HolamHaser), _) if base != 'ו' => Some('ו'),
+                    (_, Some('\u{05BB}')) => Some('ו'),
+                    (Some(VowelKind::Simple), Some('\u{05B4}'))
+                        if next.map_or(false, |next| next.shva.is_none())
+                            && next_base != Some('י') =>
+                    {
+                        Some('י')
+                    }
+                    (Some(VowelKind::Simple), _)
+                        if base == 'ו'
+                            && previous.is_some()
+                            && next.is_some()
+                            && previous.and_then(|p| p.cluster.base()) != Some('ו')
+                            && next_base != Some('ו') =>
+                    {
+                        Some('ו')
+                    }
+                    _ => None,
+                };
+                if let Some(letter) = add {
+                    key.push(primary_weight(letter), 0, 0);
+                }
+            }
+        }
+        key.tertiary.push(0);
+        key.tertiary.extend(ignored);
+        key
+    }
+
+    impl HebrewCollationKey {
+        fn push(&mut self, primary: u32, secondary: u32, tertiary: u32) {
+            self.primary.push(primary);
+            self.secondary.push(secondary);
+            self.tertiary.push(tertiary);
+        }
+    }
+
+    /// The rank of qubuts, also used for a shureq.
+    const QUBUTS_RANK: u32 = 12;
+
+    /// Characters that are only compared on the tertiary level.
+    fn is_ignorable(c: char) -> bool {
+        is_hbr_combining(c)
+            || is_bidi_control(c)
+            || matches!(
+                c,
+                '\'' | '"' | '\u{05C0}' | '\u{05C3}' | '\u{05F3}' | '\u{05F4}'
+            )
+    }
+
+    fn primary_weight(c: char) -> u32 {
+        let c = to_normal_form(c);
+        match c {
+            _ if c.is_whitespace() => 1,
+            '\u{05BE}' | '-' | '\u{2010}'..='\u{2015}' => 2,
+            '0'..='9' => 0x10 + u32::from(c) - u32::from('0'),
+            'א'..='ת' => 0x20 + u32::from(c) - u32::from('א'),
+            _ => 0x1000 + u32::from(c.to_lowercase().next().unwrap_or(c)),
+        }
+    }
+
+    /// The rank of a vowel point, in the order of the Unicode chart.
+    fn vowel_rank(c: char) -> Option<u32> {
+        match c {
+            '\u{05B0}'..='\u{05BB}' => Some(u32::from(c) - 0x05AF),
+            '\u{05C7}' => Some(13),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+    use std::cmp::Ordering;
+
+    #[test]
+    fn collation_finals_and_letters() {
+        let mut words = vec!["תות", "ךלך", "כלב", "מנה", "מן", "אב", "abc", "12"];
+        words.sort_by(|a, b| cmp_hbr(a, b));
+        assert_eq!(
+            words,
+            vec!["12", "אב", "כלב", "ךלך", "מן", "מנה", "תות", "abc"]
+        );
+    }
+
+    #[test]
+    fn collation_levels() {
+        // primary wins over vowels
+        assert_eq!(cmp_hbr("בָּא", "בד"), Ordering::Less);
+        // secondary: no vowel, then vowels in chart order
+        assert_eq!(cmp_hbr("בר", "בַּר"), Ordering::Less);
+        assert_eq!(cmp_hbr("בְּרִית", "בָּרִית"), Ordering::Less);
+        // shin before sin
+        assert_eq!(cmp_hbr("שָׁר", "שָׂר"), Ordering::Less);
+        // tertiary: accents and punctuation
+        assert_eq!(cmp_hbr("בָּרָא", "בָּרָ֣א"), Ordering::Less);
+        assert_eq!(cmp_hbr("צה״ל", "צהל"), Ordering::Greater);
+        assert_eq!(cmp_hbr("מן", "מנ"), Ordering::Greater);
+        assert_eq!(cmp_hbr("\u{FB31}", "בּ"), Ordering::Equal);
+    }
+
+    #[test]
+    fn collation_spelling_conventions() {
+        let written = CollationOptions::default();
+        let defective = CollationOptions {
+            spelling: CollationSpelling::EvenShoshan,
+        };
+        let plene = CollationOptions {
+            spelling: CollationSpelling::Academy,
+        };
+        let key = |text: &str, options: &CollationOptions| hbr_collation_key_with(text, options);
+        assert_ne!(key("שׁוֹמֵר", &written), key("שֹׁמֵר", &written));
+        assert_eq!(key("שׁוֹמֵר", &defective), key("שֹׁמֵר", &defective));
+        assert_eq!(cmp_hbr_with("שֻׁלְחָן", "שולחן", &plene), Ordering::Greater);
+        assert_eq!(cmp_hbr_with("שֻׁלְחָן", "שלחן", &plene), Ordering::Less);
+        assert_eq!(cmp_hbr_with("מִדָּה", "מידה", &plene), Ordering::Greater);
+        assert_eq!(cmp_hbr_with("תִּקְוָה", "תקווה", &plene), Ordering::Greater);
+        assert_eq!(cmp_hbr_with("תִּקְוָה", "תקווז", &plene), Ordering::Less);
+        assert_eq!(cmp_hbr_with("דָּוִד", "דוד", &defective), Ordering::Greater);
+    }
+}
@@ -0,0 +1,213 @@
+pub mod unicode_block_hebrew {
+    use crate::comparison::apf_chars;
+    use crate::final_forms::to_normal_form;
+    use hebrew_unicode_script::HebrewUnicodeScript;
+    use std::fmt;
+    use std::ops::{BitOr, BitOrAssign};
+
+    /// A set of Hebrew character types, combined with `|`.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::HebrewCharTypeSet;
+    ///
+    /// let set = HebrewCharTypeSet::ACCENT | HebrewCharTypeSet::MARK;
+    /// assert!(set.contains(HebrewCharTypeSet::ACCENT));
+    /// assert!(!set.contains(HebrewCharTypeSet::POINT_VOWEL));
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    pub struct HebrewCharTypeSet(u16);
+
+    impl HebrewCharTypeSet {
+        pub const EMPTY: HebrewCharTypeSet = HebrewCharTypeSet(0);
+        pub const ACCENT: HebrewCharTypeSet = HebrewCharTypeSet(1);
+        pub const MARK: HebrewCharTypeSet = HebrewCharTypeSet(1 << 1);
+        pub const POINT_VOWEL: HebrewCharTypeSet = HebrewCharTypeSet(1 << 2);
+        pub const POINT_SEMI_VOWEL: HebrewCharTypeSet = HebrewCharTypeSet(1 << 3);
+        pub const POINT_READING_SIGN: HebrewCharTypeSet = HebrewCharTypeSet(1 << 4);
+        pub const PUNCTUATION: HebrewCharTypeSet = HebrewCharTypeSet(1 << 5);
+        pub const YOD_TRIANGLE: HebrewCharTypeSet = HebrewCharTypeSet(1 << 6);
+        /// All points: vowels, semi-vowels and reading signs
+        pub const POINT: HebrewCharTypeSet = HebrewCharTypeSet(0b1_1100);
+        /// All points, accents and marks
+        pub const NIQQUD_AND_CANTILLATION: HebrewCharTypeSet = HebrewCharTypeSet(0b1_1111);
+
+        /// Checks if all types of `other` are in this set.
+        pub fn contains(self, other: HebrewCharTypeSet) -> bool {
+            self.0 & other.0 == other.0
+        }
+
+        /// Checks if the given character has one of the types in this set.
+        pub fn matches(self, c: char) -> bool {
+            (self.contains(Self::ACCENT) && c.is_hbr_accent())
+                || (self.contains(Self::MARK) && c.is_hbr_mark())
+                || (self.contains(Self::POINT_VOWEL) && c.is_hbr_point_vowel())
+                || (self.contains(Self::POINT_SEMI_VOWEL) && c.is_hbr_point_semi_vowel())
+                || (self.contains(Self::POINT_READING_SIGN)
+                    && (c.is_hbr_point_reading_sign() || c.is_apf_point_reading_sign()))
+                || (self.contains(Self::PUNCTUATION) && c.is_hbr_punctuation())
+                || (self.contains(Self::YOD_TRIANGLE) && c.is_hbr_yod_triangle())
+        }
+    }
+
+    impl BitOr for HebrewCharTypeSet {
+        type Output = HebrewCharTypeSet;
+
+        fn bitor(self, rhs: HebrewCharTypeSet) -> HebrewCharTypeSet {
+            HebrewCharTypeSet(self.0 | rhs.0)
+        }
+    }
+
+    impl BitOrAssign for HebrewCharTypeSet {
+        fn bitor_assign(&mut self, rhs: HebrewCharTypeSet) {
+            self.0 |= rhs.0;
+        }
+    }
+
+    /// Compares two strings while ignoring the given Hebrew character types.
+    ///
+    /// Presentation forms are compared by their decomposition. No memory is allocated.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{hbr_eq_ignoring, HebrewCharTypeSet};
+    ///
+    /// assert!(hbr_eq_ignoring("בְּרֵאשִׁ֖ית", "בראשית", HebrewCharTypeSet::NIQQUD_AND_CANTILLATION));
+    /// assert!(hbr_eq_ignoring("בְּרֵאשִׁ֖ית", "בְּרֵאשִׁית", HebrewCharTypeSet::ACCENT));
+    /// assert!(!hbr_eq_ignoring("בְּרֵאשִׁ֖ית", "בראשית", HebrewCharTypeSet::ACCENT));
+    /// assert!(hbr_eq_ignoring("\u{FB31}", "בּ", HebrewCharTypeSet::EMPTY));
+    /// ```
+    pub fn hbr_eq_ignoring(a: &str, b: &str, ignore: HebrewCharTypeSet) -> bool {
+        apf_chars(a)
+            .filter(|&c| !ignore.matches(c))
+            .eq(apf_chars(b).filter(|&c| !ignore.matches(c)))
+    }
+
+    /// A key for searching and sorting Hebrew text regardless of how it is written.
+    ///
+    /// The key is built in a single pass: presentation forms are decomposed, points, accents and marks are removed,
+    /// final letters are replaced by their normal form and maqaf, hyphens and dashes become a hyphen-minus.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::HebrewSearchKey;
+    ///
+    /// let pointed = HebrewSearchKey::new("עַל־פְּנֵי הַמָּיִם");
+    /// let typed = HebrewSearchKey::new("על-פני המים");
+    /// assert_eq!(pointed, typed);
+    /// assert_eq!(typed.as_str(), "על-פני המימ");
+    /// ```
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+    pub struct HebrewSearchKey(String);
+
+    impl HebrewSearchKey {
+        /// Builds the search key of the given text.
+        pub fn new(text: &str) -> HebrewSearchKey {
+            let mut key = String::with_capacity(text.len());
+            for c in apf_chars(text) {
+                match c {
+                    '\u{05BE}' | '\u{2010}'..='\u{2015}' | '\u{2212}' => key.push('-'),
+                    _ if c.is_hbr_accent()
+                        || c.is_hbr_mark()
+                        || c.is_hbr_point()
+                        || c.is_apf_point_reading_sign() => {}
+                    _ => key.push(to_normal_form(c)),
+                }
+            }
+            HebrewSearchKey(key)
+        }
+
+        /// Returns the folded text of the key.
+        pub fn as_str(&self) -> &str {
+            &self.0
+        }
+
+        /// Checks if the given key occurs in this key.
+        pub fn contains(&self, other: &HebrewSearchKey) -> bool {
+            self.0.contains(other.as_str())
+        }
+    }
+
+    impl From<&str> for HebrewSearchKey {
+        fn from(text: &str) -> Self {
+            HebrewSearchKey::new(text)
+        }
+    }
+
+    impl fmt::Display for HebrewSearchKey {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(&self.0)
+        }
+    }
+}
+
+use crate::presentation_forms::decompose_apf_char;
+
+/// Iterates over the characters of a string with presentation forms decomposed.
+pub(crate) fn apf_chars(text: &str) -> impl Iterator<Item = char> + '_ {
+    text.chars().flat_map(|c| {
+        let (single, decomposed) = match decompose_apf_char(c) {
+            Some(decomposed) => (None, decomposed),
+            None => (Some(c), ""),
+        };
+        single.into_iter().chain(decomposed.chars())
+    })
+}
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn eq_ignoring_points_only() {
+        let points = HebrewCharTypeSet::POINT;
+        assert!(hbr_eq_ignoring("שָׁלוֹם", "שלום", points));
+        assert!(!hbr_eq_ignoring("שָׁ֫לוֹם", "שלום", points));
+        assert!(hbr_eq_ignoring(
+            "שָׁ֫לוֹם",
+            "שלום",
+            points | HebrewCharTypeSet::ACCENT
+        ));
+        assert!(!hbr_eq_ignoring("שלום", "שלו", points));
+    }
+
+    #[test]
+    fn char_type_set_union() {
+        let mut set = HebrewCharTypeSet::EMPTY;
+        set |= HebrewCharTypeSet::POINT_VOWEL;
+        set |= HebrewCharTypeSet::POINT_SEMI_VOWEL;
+        set |= HebrewCharTypeSet::POINT_READING_SIGN;
+        assert_eq!(set, HebrewCharTypeSet::POINT);
+        assert!(HebrewCharTypeSet::NIQQUD_AND_CANTILLATION.contains(set));
+        assert!(HebrewCharTypeSet::PUNCTUATION.matches('\u{05C3}'));
+    }
+
+    #[test]
+    fn search_key_folds_all_forms() {
+        let keys: HashSet<HebrewSearchKey> = [
+            "מֶ֣לֶךְ",
+            "מלך",
+            "מלכ",
+            "\u{FB3E}ל\u{FB3A}",
+            "\u{FB3E}\u{FB25}ך",
+        ]
+        .iter()
+        .map(|&text| HebrewSearchKey::new(text))
+        .collect();
+        assert_eq!(keys.len(), 1);
+    }
+
+    #[test]
+    fn search_key_order_and_contains() {
+        let mut keys: Vec<HebrewSearchKey> = ["גַּן", "אָב", "בַּיִת"]
+            .iter()
+            .map(|&text| HebrewSearchKey::from(text))
+            .collect();
+        keys.sort();
+        let sorted: Vec<&str> = keys.iter().map(|key| key.as_str()).collect();
+        assert_eq!(sorted, vec!["אב", "בית", "גנ"]);
+        let verse = HebrewSearchKey::new("בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים");
+        assert!(verse.contains(&HebrewSearchKey::new("ברא")));
+    }
+}
@@ -0,0 +1,875 @@
+pub mod unicode_block_hebrew {
+    use crate::conjugation::{conjugate, root_letters, templates};
+    use crate::roots::weaknesses;
+    use crate::{to_final_forms, RootWeakness};
+    use std::error::Error;
+    use std::fmt;
+
+    /// The verb patterns of Hebrew.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Binyan {
+        Qal,
+        Niphal,
+        Piel,
+        Pual,
+        Hiphil,
+        Hophal,
+        Hitpael,
+    }
+
+    /// The tenses, moods and non-finite forms of a Hebrew verb.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum VerbForm {
+        Perfect,
+        Imperfect,
+        /// Only for the active binyanim
+        Imperative,
+        InfinitiveConstruct,
+        InfinitiveAbsolute,
+        /// The active participle, or the only participle of the other binyanim than qal
+        Participle,
+        /// The passive participle of qal
+        PassiveParticiple,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Person {
+        First,
+        Second,
+        Third,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Gender {
+        Masculine,
+        Feminine,
+        /// Both genders share the form, such as the first person
+        Common,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Number {
+        Singular,
+        Plural,
+    }
+
+    /// One pointed form of a [`HebrewParadigm`]; person, gender and number are absent where the form has none.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ConjugatedForm {
+        pub form: VerbForm,
+        pub person: Option<Person>,
+        pub gender: Option<Gender>,
+        pub number: Option<Number>,
+        /// The fully pointed form, with the points of every letter in canonical order
+        pub text: String,
+    }
+
+    /// All forms of a root in one binyan, see [`conjugate_hbr`].
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct HebrewParadigm {
+        /// The root letters, ending in a final letter where needed
+        pub root: String,
+        pub binyan: Binyan,
+        /// The forms in the order of the grammar books: perfect, imperfect, imperative, infinitives and
+        /// participles, every tense starting with the third person masculine singular
+        pub forms: Vec<ConjugatedForm>,
+    }
+
+    impl HebrewParadigm {
+        /// Returns the text of the form with the given tense, person, gender and number.
+        pub fn get(
+            &self,
+            form: VerbForm,
+            person: Option<Person>,
+            gender: Option<Gender>,
+            number: Option<Number>,
+        ) -> Option<&str> {
+            self.forms
+                .iter()
+                .find(|f| {
+                    f.form == form && f.person == person && f.gender == gender && f.number == number
+                })
+                .map(|f| f.text.as_str())
+        }
+    }
+
+    /// The error returned when [`conjugate_hbr`] cannot conjugate a root.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum ConjugationError {
+        /// The root has the given number of letters instead of three
+        NotTriliteral(usize),
+        /// The root has a weak letter, which is not supported yet
+        WeakRoot(RootWeakness),
+        /// The root ends in the given vav or yod (ל״ו or ל״י), which is not supported yet
+        FinalVavYod(char),
+        /// The root is irregular, such as נתן, and its forms cannot be derived from the templates
+        IrregularRoot,
+        /// The root has the given guttural letter (א, ה, ח or ע, or a ר that the binyan doubles),
+        /// which is not supported yet
+        GutturalRoot(char),
+    }
+
+    impl fmt::Display for ConjugationError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                ConjugationError::NotTriliteral(letters) => {
+                    write!(f, "the root has {} letters instead of 3", letters)
+                }
+                ConjugationError::WeakRoot(weakness) => {
+                    write!(f, "weak roots ({:?}) are not supported", weakness)
+                }
+                ConjugationError::FinalVavYod(letter) => {
+                    write!(f, "roots ending in {} are not supported", letter)
+                }
+                ConjugationError::IrregularRoot => write!(f, "irregular roots are not supported"),
+                ConjugationError::GutturalRoot(letter) => {
+                    write!(f, "roots with the guttural {} are not supported", letter)
+                }
+            }
+        }
+    }
+
+    impl Error for ConjugationError {}
+
+    /// Conjugates a three letter root in the given binyan, returning the fully pointed paradigm.
+    ///
+    /// The paradigm has the perfect and imperfect in all persons, genders and numbers, the imperative
+    /// (not for pual and hophal), the infinitive construct and absolute and the participles.
+    /// Dagesh lene is added after a silent shva and at the start of a word, a last ת or נ of the root
+    /// merges with the ת or נ of an ending (כָּרַתָּ, שָׁכַנּוּ), a final kaf gets its shva and the last letter
+    /// its final form. In hitpael the ת swaps places with a sibilant first root letter (הִשְׁתַּמֵּשׁ,
+    /// הִצְטַלֵּם, הִזְדַּקֵּן) and is assimilated into ד, ט and ת (הִדַּבֵּר).
+    ///
+    /// Of the weak roots only those with a first nun (פ״נ) are supported, outside qal: a nun without
+    /// a vowel is assimilated into the next root letter (נִפַּל, הִפִּיל, הֻפַּל). In qal the imperfect vowel
+    /// and the imperative (יִפֹּל and נְפֹל, but יִגַּשׁ and גַּשׁ) depend on the root, so it returns an error,
+    /// as does a first nun before ר, which cannot be doubled. The irregular נתן returns an error in every binyan.
+    /// Roots with another weak letter (see [`crate::RootWeakness`], a first vav included) or a guttural
+    /// letter return an error, as do a last vav or yod and a ר that the binyan doubles
+    /// (the second root letter in piel, pual and hitpael, the first in niphal).
+    /// Qal uses the imperfect with holam (יִכְתֹּב) and hophal the modern spelling with qubuts (הֻכְתַּב).
+    /// A ש of the root is a shin, unless the root is given with a sin dot.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::*;
+    ///
+    /// let paradigm = conjugate_hbr("שמר", Binyan::Qal).unwrap();
+    /// assert_eq!(paradigm.forms.len(), 33);
+    /// assert!(paradigm.forms.iter().all(|f| validate_hbr(&f.text).is_empty()));
+    /// let imperfect = paradigm.get(
+    ///     VerbForm::Imperfect,
+    ///     Some(Person::Third),
+    ///     Some(Gender::Masculine),
+    ///     Some(Number::Singular),
+    /// );
+    /// assert_eq!(imperfect.map(remove_hbr_point), Some("ישמר".into()));
+    ///
+    /// let hitpael = conjugate_hbr("שמש", Binyan::Hitpael).unwrap();
+    /// assert_eq!(remove_hbr_point(&hitpael.forms[0].text), "השתמש");
+    ///
+    /// let hiphil = conjugate_hbr("נפל", Binyan::Hiphil).unwrap();
+    /// assert_eq!(remove_hbr_point(&hiphil.forms[0].text), "הפיל");
+    ///
+    /// assert_eq!(
+    ///     conjugate_hbr("קום", Binyan::Qal),
+    ///     Err(ConjugationError::WeakRoot(RootWeakness::MiddleVavYod))
+    /// );
+    /// ```
+    pub fn conjugate_hbr(root: &str, binyan: Binyan) -> Result<HebrewParadigm, ConjugationError> {
+        let letters = root_letters(root);
+        if letters.len() != 3 {
+            return Err(ConjugationError::NotTriliteral(letters.len()));
+        }
+        let normal = [letters[0].0, letters[1].0, letters[2].0];
+        if normal == ['נ', 'ת', 'נ'] {
+            return Err(ConjugationError::IrregularRoot);
+        }
+        if normal[0] == 'נ' && (binyan == Binyan::Qal || normal[1] == 'ר') {
+            return Err(ConjugationError::WeakRoot(RootWeakness::InitialNun));
+        }
+        if let Some(weakness) = weaknesses(&normal)
+            .into_iter()
+            .find(|&weakness| weakness != RootWeakness::InitialNun)
+        {
+            return Err(ConjugationError::WeakRoot(weakness));
+        }
+        if normal[0] == 'ו' {
+            return Err(ConjugationError::WeakRoot(RootWeakness::InitialYod));
+        }
+        if normal[2] == 'ו' || normal[2] == 'י' {
+            return Err(ConjugationError::FinalVavYod(normal[2]));
+        }
+        if let Some(&guttural) = normal.iter().find(|c| "אהחע".contains(**c)) {
+            return Err(ConjugationError::GutturalRoot(guttural));
+        }
+        // ר cannot be doubled: the second root letter in piel, pual and hitpael, the first in niphal
+        let doubled = match binyan {
+            Binyan::Piel | Binyan::Pual | Binyan::Hitpael => Some(1),
+            Binyan::Niphal => Some(0),
+            _ => None,
+        };
+        if doubled.map_or(false, |i| normal[i] == 'ר') {
+            return Err(ConjugationError::GutturalRoot('ר'));
+        }
+
+        let forms = templates(binyan)
+            .iter()
+            .map(|&(form, pgn, template)| {
+                let mut conjugated = ConjugatedForm {
+                    form,
+                    person: None,
+                    gender: None,
+                    number: None,
+                    text: conjugate(template, &letters, binyan),
+                };
+                for c in pgn.chars() {
+                    match c {
+                        '1' => conjugated.person = Some(Person::First),
+                        '2' => conjugated.person = Some(Person::Second),
+                        '3' => conjugated.person = Some(Person::Third),
+                        'm' => conjugated.gender = Some(Gender::Masculine),
+                        'f' => conjugated.gender = Some(Gender::Feminine),
+                        'c' => conjugated.gender = Some(Gender::Common),
+                        's' => conjugated.number = Some(Number::Singular),
+                        _ => conjugated.number = Some(Number::Plural),
+                    }
+                }
+                conjugated
+            })
+            .collect();
+        Ok(HebrewParadigm {
+            root: to_final_forms(&normal.iter().collect::<String>()).into_owned(),
+            binyan,
+            forms,
+        })
+    }
+}
+
+use crate::clusters::is_hbr_combining;
+use crate::final_forms::{to_final_form, to_normal_form};
+use crate::validation::combining_class;
+use crate::{get_hbr_clusters, Binyan, VerbForm};
+use hebrew_unicode_script::HebrewUnicodeScript;
+
+const SHVA: char = '\u{05B0}';
+const DAGESH: char = '\u{05BC}';
+const SHIN_DOT: char = '\u{05C1}';
+
+/// A form: the tense, the person, gender and number as in `3ms`, and the template.
+///
+/// In a template the digits 1, 2 and 3 are the root letters and `:` is a vocal shva,
+/// after which no dagesh lene is added. Dagesh lene is added by [`conjugate`] and not written.
+type Template = (VerbForm, &'static str, &'static str);
+
+const QAL: [Template; 33] = [
+    (VerbForm::Perfect, "3ms", "1ָ2ַ3"),
+    (VerbForm::Perfect, "3fs", "1ָ2:3ָה"),
+    (VerbForm::Perfect, "2ms", "1ָ2ַ3ְתָ"),
+    (VerbForm::Perfect, "2fs", "1ָ2ַ3ְתְ"),
+    (VerbForm::Perfect, "1cs", "1ָ2ַ3ְתִי"),
+    (VerbForm::Perfect, "3cp", "1ָ2:3וּ"),
+    (VerbForm::Perfect, "2mp", "1:2ַ3ְתֶם"),
+    (VerbForm::Perfect, "2fp", "1:2ַ3ְתֶן"),
+    (VerbForm::Perfect, "1cp", "1ָ2ַ3ְנוּ"),
+    (VerbForm::Imperfect, "3ms", "יִ1ְ2ֹ3"),
+    (VerbForm::Imperfect, "3fs", "תִ1ְ2ֹ3"),
+    (VerbForm::Imperfect, "2ms", "תִ1ְ2ֹ3"),
+    (VerbForm::Imperfect, "2fs", "תִ1ְ2:3ִי"),
+    (VerbForm::Imperfect, "1cs", "אֶ1ְ2ֹ3"),
+    (VerbForm::Imperfect, "3mp", "יִ1ְ2:3וּ"),
+    (VerbForm::Imperfect, "3fp", "תִ1ְ2ֹ3ְנָה"),
+    (VerbForm::Imperfect, "2mp", "תִ1ְ2:3וּ"),
+    (VerbForm::Imperfect, "2fp", "תִ1ְ2ֹ3ְנָה"),
+    (VerbForm::Imperfect, "1cp", "נִ1ְ2ֹ3"),
+    (VerbForm::Imperative, "2ms", "1:2ֹ3"),
+    (VerbForm::Imperative, "2fs", "1ִ2:3ִי"),
+    (VerbForm::Imperative, "2mp", "1ִ2:3וּ"),
+    (VerbForm::Imperative, "2fp", "1:2ֹ3ְנָה"),
+    (VerbForm::InfinitiveConstruct, "", "1:2ֹ3"),
+    (VerbForm::InfinitiveAbsolute, "", "1ָ2וֹ3"),
+    (VerbForm::Participle, "ms", "1וֹ2ֵ3"),
+    (VerbForm::Participle, "fs", "1וֹ2ֶ3ֶת"),
+    (VerbForm::Participle, "mp", "1וֹ2:3ִים"),
+    (VerbForm::Participle, "fp", "1וֹ2:3וֹת"),
+    (VerbForm::PassiveParticiple, "ms", "1ָ2וּ3"),
+    (VerbForm::PassiveParticiple, "fs", "1:2וּ3ָה"),
+    (VerbForm::PassiveParticiple, "mp", "1:2וּ3ִים"),
+    (VerbForm::PassiveParticiple, "fp", "1:2וּ3וֹת"),
+];
+
+const NIPHAL: [Template; 29] = [
+    (VerbForm::Perfect, "3ms", "נִ1ְ2ַ3"),
+    (VerbForm::Perfect, "3fs", "נִ1ְ2:3ָה"),
+    (VerbForm::Perfect, "2ms", "נִ1ְ2ַ3ְתָ"),
+    (VerbForm::Perfect, "2fs", "נִ1ְ2ַ3ְתְ"),
+    (VerbForm::Perfect, "1cs", "נִ1ְ2ַ3ְתִי"),
+    (VerbForm::Perfect, "3cp", "נִ1ְ2:3וּ"),
+    (VerbForm::Perfect, "2mp", "נִ1ְ2ַ3ְתֶם"),
+    (VerbForm::Perfect, "2fp", "נִ1ְ2ַ3ְתֶן"),
+    (VerbForm::Perfect, "1cp", "נִ1ְ2ַ3ְנוּ"),
+    (VerbForm::Imperfect, "3ms", "יִ1ָּ2ֵ3"),
+    (VerbForm::Imperfect, "3fs", "תִ1ָּ2ֵ3"),
+    (VerbForm::Imperfect, "2ms", "תִ1ָּ2ֵ3"),
+    (VerbForm::Imperfect, "2fs", "תִ1ָּ2:3ִי"),
+    (VerbForm::Imperfect, "1cs", "אֶ1ָּ2ֵ3"),
+    (VerbForm::Imperfect, "3mp", "יִ1ָּ2:3וּ"),
+    (VerbForm::Imperfect, "3fp", "תִ1ָּ2ַ3ְנָה"),
+    (VerbForm::Imperfect, "2mp", "תִ1ָּ2:3וּ"),
+    (VerbForm::Imperfect, "2fp", "תִ1ָּ2ַ3ְנָה"),
+    (VerbForm::Imperfect, "1cp", "נִ1ָּ2ֵ3"),
+    (VerbForm::Imperative, "2ms", "הִ1ָּ2ֵ3"),
+    (VerbForm::Imperative, "2fs", "הִ1ָּ2:3ִי"),
+    (VerbForm::Imperative, "2mp", "הִ1ָּ2:3וּ"),
+    (VerbForm::Imperative, "2fp", "הִ1ָּ2ַ3ְנָה"),
+    (VerbForm::InfinitiveConstruct, "", "הִ1ָּ2ֵ3"),
+    (VerbForm::InfinitiveAbsolute, "", "נִ1ְ2ֹ3"),
+    (VerbForm::Participle, "ms", "נִ1ְ2ָ3"),
+    (VerbForm::Participle, "fs", "נִ1ְ2ֶ3ֶת"),
+    (VerbForm::Participle, "mp", "נִ1ְ2ָ3ִים"),
+    (VerbForm::Participle, "fp", "נִ1ְ2ָ3וֹת"),
+];
+
+const PIEL: [Template; 29] = [
+    (VerbForm::Perfect, "3ms", "1ִ2ֵּ3"),
+    (VerbForm::Perfect, "3fs", "1ִ2ּ:3ָה"),
+    (VerbForm::Perfect, "2ms", "1ִ2ַּ3ְתָ"),
+    (VerbForm::Perfect, "2fs", "1ִ2ַּ3ְתְ"),
+    (VerbForm::Perfect, "1cs", "1ִ2ַּ3ְתִי"),
+    (VerbForm::Perfect, "3cp", "1ִ2ּ:3וּ"),
+    (VerbForm::Perfect, "2mp", "1ִ2ַּ3ְתֶם"),
+    (VerbForm::Perfect, "2fp", "1ִ2ַּ3ְתֶן"),
+    (VerbForm::Perfect, "1cp", "1ִ2ַּ3ְנוּ"),
+    (VerbForm::Imperfect, "3ms", "י:1ַ2ֵּ3"),
+    (VerbForm::Imperfect, "3fs", "ת:1ַ2ֵּ3"),
+    (VerbForm::Imperfect, "2ms", "ת:1ַ2ֵּ3"),
+    (VerbForm::Imperfect, "2fs", "ת:1ַ2ּ:3ִי"),
+    (VerbForm::Imperfect, "1cs", "אֲ1ַ2ֵּ3"),
+    (VerbForm::Imperfect, "3mp", "י:1ַ2ּ:3וּ"),
+    (VerbForm::Imperfect, "3fp", "ת:1ַ2ֵּ3ְנָה"),
+    (VerbForm::Imperfect, "2mp", "ת:1ַ2ּ:3וּ"),
+    (VerbForm::Imperfect, "2fp", "ת:1ַ2ֵּ3ְנָה"),
+    (VerbForm::Imperfect, "1cp", "נ:1ַ2ֵּ3"),
+    (VerbForm::Imperative, "2ms", "1ַ2ֵּ3"),
+    (VerbForm::Imperative, "2fs", "1ַ2ּ:3ִי"),
+    (VerbForm::Imperative, "2mp", "1ַ2ּ:3וּ"),
+    (VerbForm::Imperative, "2fp", "1ַ2ֵּ3ְנָה"),
+    (VerbForm::InfinitiveConstruct, "", "1ַ2ֵּ3"),
+    (VerbForm::InfinitiveAbsolute, "", "1ַ2ֹּ3"),
+    (VerbForm::Participle, "ms", "מ:1ַ2ֵּ3"),
+    (VerbForm::Participle, "fs", "מ:1ַ2ֶּ3ֶת"),
+    (VerbForm::Participle, "mp", "מ:1ַ2ּ:3ִים"),
+    (VerbForm::Participle, "fp", "מ:1ַ2ּ:3וֹת"),
+];
+
+const PUAL: [Template; 25] = [
+    (VerbForm::Perfect, "3ms", "1ֻ2ַּ3"),
+    (VerbForm::Perfect, "3fs", "1ֻ2ּ:3ָה"),
+    (VerbForm::Perfect, "2ms", "1ֻ2ַּ3ְתָ"),
+    (VerbForm::Perfect, "2fs", "1ֻ2ַּ3ְתְ"),
+    (VerbForm::Perfect, "1cs", "1ֻ2ַּ3ְתִי"),
+    (VerbForm::Perfect, "3cp", "1ֻ2ּ:3וּ"),
+    (VerbForm::Perfect, "2mp", "1ֻ2ַּ3ְתֶם"),
+    (VerbForm::Perfect, "2fp", "1ֻ2ַּ3ְתֶן"),
+    (VerbForm::Perfect, "1cp", "1ֻ2ַּ3ְנוּ"),
+    (VerbForm::Imperfect, "3ms", "י:1ֻ2ַּ3"),
+    (VerbForm::Imperfect, "3fs", "ת:1ֻ2ַּ3"),
+    (VerbForm::Imperfect, "2ms", "ת:1ֻ2ַּ3"),
+    (VerbForm::Imperfect, "2fs", "ת:1ֻ2ּ:3ִי"),
+    (VerbForm::Imperfect, "1cs", "אֲ1ֻ2ַּ3"),
+    (VerbForm::Imperfect, "3mp", "י:1ֻ2ּ:3וּ"),
+    (VerbForm::Imperfect, "3fp", "ת:1ֻ2ַּ3ְנָה"),
+    (VerbForm::Imperfect, "2mp", "ת:1ֻ2ּ:3וּ"),
+    (VerbForm::Imperfect, "2fp", "ת:1ֻ2ַּ3ְנָה"),
+    (VerbForm::Imperfect, "1cp", "נ:1ֻ2ַּ3"),
+    (VerbForm::InfinitiveConstruct, "", "1ֻ2ַּ3"),
+    (VerbForm::InfinitiveAbsolute, "", "1ֻ2ֹּ3"),
+    (VerbForm::Participle, "ms", "מ:1ֻ2ָּ3"),
+    (VerbForm::Participle, "fs", "מ:1ֻ2ֶּ3ֶת"),
+    (VerbForm::Participle, "mp", "מ:1ֻ2ָּ3ִים"),
+    (VerbForm::Participle, "fp", "מ:1ֻ2ָּ3וֹת"),
+];
+
+const HIPHIL: [Template; 29] = [
+    (VerbForm::Perfect, "3ms", "הִ1ְ2ִי3"),
+    (VerbForm::Perfect, "3fs", "הִ1ְ2ִי3ָה"),
+    (VerbForm::Perfect, "2ms", "הִ1ְ2ַ3ְתָ"),
+    (VerbForm::Perfect, "2fs", "הִ1ְ2ַ3ְתְ"),
+    (VerbForm::Perfect, "1cs", "הִ1ְ2ַ3ְתִי"),
+    (VerbForm::Perfect, "3cp", "הִ1ְ2ִי3וּ"),
+    (VerbForm::Perfect, "2mp", "הִ1ְ2ַ3ְתֶם"),
+    (VerbForm::Perfect, "2fp", "הִ1ְ2ַ3ְתֶן"),
+    (VerbForm::Perfect, "1cp", "הִ1ְ2ַ3ְנוּ"),
+    (VerbForm::Imperfect, "3ms", "יַ1ְ2ִי3"),
+    (VerbForm::Imperfect, "3fs", "תַ1ְ2ִי3"),
+    (VerbForm::Imperfect, "2ms", "תַ1ְ2ִי3"),
+    (VerbForm::Imperfect, "2fs", "תַ1ְ2ִי3ִי"),
+    (VerbForm::Imperfect, "1cs", "אַ1ְ2ִי3"),
+    (VerbForm::Imperfect, "3mp", "יַ1ְ2ִי3וּ"),
+    (VerbForm::Imperfect, "3fp", "תַ1ְ2ֵ3ְנָה"),
+    (VerbForm::Imperfect, "2mp", "תַ1ְ2ִי3וּ"),
+    (VerbForm::Imperfect, "2fp", "תַ1ְ2ֵ3ְנָה"),
+    (VerbForm::Imperfect, "1cp", "נַ1ְ2ִי3"),
+    (VerbForm::Imperative, "2ms", "הַ1ְ2ֵ3"),
+    (VerbForm::Imperative, "2fs", "הַ1ְ2ִי3ִי"),
+    (VerbForm::Imperative, "2mp", "הַ1ְ2ִי3וּ"),
+    (VerbForm::Imperative, "2fp", "הַ1ְ2ֵ3ְנָה"),
+    (VerbForm::InfinitiveConstruct, "", "הַ1ְ2ִי3"),
+    (VerbForm::InfinitiveAbsolute, "", "הַ1ְ2ֵ3"),
+    (VerbForm::Participle, "ms", "מַ1ְ2ִי3"),
+    (VerbForm::Participle, "fs", "מַ1ְ2ִי3ָה"),
+    (VerbForm::Participle, "mp", "מַ1ְ2ִי3ִים"),
+    (VerbForm::Participle, "fp", "מַ1ְ2ִי3וֹת"),
+];
+
+const HOPHAL: [Template; 25] = [
+    (VerbForm::Perfect, "3ms", "הֻ1ְ2ַ3"),
+    (VerbForm::Perfect, "3fs", "הֻ1ְ2:3ָה"),
+    (VerbForm::Perfect, "2ms", "הֻ1ְ2ַ3ְתָ"),
+    (VerbForm::Perfect, "2fs", "הֻ1ְ2ַ3ְתְ"),
+    (VerbForm::Perfect, "1cs", "הֻ1ְ2ַ3ְתִי"),
+    (VerbForm::Perfect, "3cp", "הֻ1ְ2:3וּ"),
+    (VerbForm::Perfect, "2mp", "הֻ1ְ2ַ3ְתֶם"),
+    (VerbForm::Perfect, "2fp", "הֻ1ְ2ַ3ְתֶן"),
+    (VerbForm::Perfect, "1cp", "הֻ1ְ2ַ3ְנוּ"),
+    (VerbForm::Imperfect, "3ms", "יֻ1ְ2ַ3"),
+    (VerbForm::Imperfect, "3fs", "תֻ1ְ2ַ3"),
+    (VerbForm::Imperfect, "2ms", "תֻ1ְ2ַ3"),
+    (VerbForm::Imperfect, "2fs", "תֻ1ְ2:3ִי"),
+    (VerbForm::Imperfect, "1cs", "אֻ1ְ2ַ3"),
+    (VerbForm::Imperfect, "3mp", "יֻ1ְ2:3וּ"),
+    (VerbForm::Imperfect, "3fp", "תֻ1ְ2ַ3ְנָה"),
+    (VerbForm::Imperfect, "2mp", "תֻ1ְ2:3וּ"),
+    (VerbForm::Imperfect, "2fp", "תֻ1ְ2ַ3ְנָה"),
+    (VerbForm::Imperfect, "1cp", "נֻ1ְ2ַ3"),
+    (VerbForm::InfinitiveConstruct, "", "הֻ1ְ2ַ3"),
+    (VerbForm::InfinitiveAbsolute, "", "הֻ1ְ2ֵ3"),
+    (VerbForm::Participle, "ms", "מֻ1ְ2ָ3"),
+    (VerbForm::Participle, "fs", "מֻ1ְ2ֶ3ֶת"),
+    (VerbForm::Participle, "mp", "מֻ1ְ2ָ3ִים"),
+    (VerbForm::Participle, "fp", "מֻ1ְ2ָ3וֹת"),
+];
+
+const HITPAEL: [Template; 29] = [
+    (VerbForm::Perfect, "3ms", "הִתְ1ַ2ֵּ3"),
+    (VerbForm::Perfect, "3fs", "הִתְ1ַ2ּ:3ָה"),
+    (VerbForm::Perfect, "2ms", "הִתְ1ַ2ַּ3ְתָ"),
+    (VerbForm::Perfect, "2fs", "הִתְ1ַ2ַּ3ְתְ"),
+    (VerbForm::Perfect, "1cs", "הִתְ1ַ2ַּ3ְתִי"),
+    (VerbForm::Perfect, "3cp", "הִתְ1ַ2ּ:3וּ"),
+    (VerbForm::Perfect, "2mp", "הִתְ1ַ2ַּ3ְתֶם"),
+    (VerbForm::Perfect, "2fp", "הִתְ1ַ2ַּ3ְתֶן"),
+    (VerbForm::Perfect, "1cp", "הִתְ1ַ2ַּ3ְנוּ"),
+    (VerbForm::Imperfect, "3ms", "יִתְ1ַ2ֵּ3"),
+    (VerbForm::Imperfect, "3fs", "תִתְ1ַ2ֵּ3"),
+    (VerbForm::Imperfect, "2ms", "תִתְ1ַ2ֵּ3"),
+    (VerbForm::Imperfect, "2fs", "תִתְ1ַ2ּ:3ִי"),
+    (VerbForm::Imperfect, "1cs", "אֶתְ1ַ2ֵּ3"),
+    (VerbForm::Imperfect, "3mp", "יִתְ1ַ2ּ:3וּ"),
+    (VerbForm::Imperfect, "3fp", "תִתְ1ַ2ֵּ3ְנָה"),
+    (VerbForm::Imperfect, "2mp", "תִתְ1ַ2ּ:3וּ"),
+    (VerbForm::Imperfect, "2fp", "תִתְ1ַ2ֵּ3ְנָה"),
+    (VerbForm::Imperfect, "1cp", "נִתְ1ַ2ֵּ3"),
+    (VerbForm::Imperative, "2ms", "הִתְ1ַ2ֵּ3"),
+    (VerbForm::Imperative, "2fs", "הִתְ1ַ2ּ:3ִי"),
+    (VerbForm::Imperative, "2mp", "הִתְ1ַ2ּ:3וּ"),
+    (VerbForm::Imperative, "2fp", "הִתְ1ַ2ֵּ3ְנָה"),
+    (VerbForm::InfinitiveConstruct, "", "הִתְ1ַ2ֵּ3"),
+    (VerbForm::InfinitiveAbsolute, "", "הִתְ1ַ2ֵּ3"),
+    (VerbForm::Participle, "ms", "מִתְ1ַ2ֵּ3"),
+    (VerbForm::Participle, "fs", "מִתְ1ַ2ֶּ3ֶת"),
+    (VerbForm::Participle, "mp", "מִתְ1ַ2ּ:3ִים"),
+    (VerbForm::Participle, "fp", "מִתְ1ַ2ּ:3וֹת"),
+];
+
+pub(crate) fn templates(binyan: Binyan) -> &'static [Template] {
+    match binyan {
+        Binyan::Qal => &QAL,
+        Binyan::Niphal => &NIPHAL,
+        Binyan::Piel => &PIEL,
+        Binyan::Pual => &PUAL,
+        Binyan::Hiphil => &HIPHIL,
+        Binyan::Hophal => &HOPHAL,
+        Binyan::Hitpael => &HITPAEL,
+    }
+}
+
+/// The consonants of a root in normal form with their shin or sin dot; other characters are skipped.
+pub(crate) fn root_letters(root: &str) -> Vec<(char, Option<char>)> {
+    get_hbr_clusters(root)
+        .iter()
+        .filter_map(|cluster| {
+            let base = cluster.base().filter(|c| c.is_hbr_consonant())?;
+            let dot = cluster
+                .marks()
+                .find(|&c| c == '\u{05C1}' || c == '\u{05C2}');
+            Some((to_normal_form(base), dot))
+        })
+        .collect()
+}
+
+/// A letter of a form being built, with the root letter it stands for.
+struct Unit {
+    letter: char,
+    marks: Vec<char>,
+    slot: Option<usize>,
+    /// The shva of the letter is vocal
+    vocal: bool,
+}
+
+impl Unit {
+    fn silent_shva(&self) -> bool {
+        self.marks.contains(&SHVA) && !self.vocal
+    }
+}
+
+/// Fills a template with the root letters and writes the result in canonical order.
+pub(crate) fn conjugate(template: &str, root: &[(char, Option<char>)], binyan: Binyan) -> String {
+    let mut units: Vec<Unit> = Vec::new();
+    for c in template.chars() {
+        match (c, units.last_mut()) {
+            (':', Some(unit)) => {
+                unit.marks.push(SHVA);
+                unit.vocal = true;
+            }
+            (c, Some(unit)) if is_hbr_combining(c) => unit.marks.push(c),
+            (c, _) => {
+                let slot = c.to_digit(10).map(|d| d as usize - 1);
+                let (letter, dot) = slot.map_or((c, None), |s| root[s]);
+                let mut marks = Vec::new();
+                if letter == 'ש' {
+                    marks.push(dot.unwrap_or(SHIN_DOT));
+                }
+                units.push(Unit {
+                    letter,
+                    marks,
+                    slot,
+                    vocal: false,
+                });
+            }
+        }
+    }
+
+    if binyan == Binyan::Hitpael {
+        if let Some(i) = (1..units.len()).find(|&i| units[i].slot == Some(0)) {
+            let first = units[i].letter;
+            let swapped = match first {
+                'ש' | 'ס' => Some('ת'),
+                'צ' => Some('ט'),
+                'ז' => Some('ד'),
+                _ => None,
+            };
+            if let Some(infix) = swapped {
+                // the sibilant swaps places with the ת (voiced or emphatic like the sibilant)
+                let sibilant_marks: Vec<char> = units[i]
+                    .marks
+                    .iter()
+                    .copied()
+                    .filter(|&m| m == '\u{05C1}' || m == '\u{05C2}')
+                    .collect();
+                let vowels: Vec<char> = units[i]
+                    .marks
+                    .iter()
+                    .copied()
+                    .filter(|m| !sibilant_marks.contains(m))
+                    .collect();
+                units[i - 1].letter = first;
+                units[i - 1].marks.extend(sibilant_marks);
+                units[i].letter = infix;
+                units[i].marks = vowels;
+                units[i - 1].slot = Some(0);
+                units[i].slot = None;
+            } else if matches!(first, 'ד' | 'ט' | 'ת') {
+                // the ת is assimilated and doubles the first root letter
+                units.remove(i - 1);
+                units[i - 1].marks.push(DAGESH);
+            }
+        }
+    }
+
+    // a first נ of the root without a vowel is assimilated and doubles the next letter
+    if let Some(i) = (0..units.len().saturating_sub(1)).find(|&i| units[i].slot == Some(0)) {
+        if units[i].letter == 'נ' && units[i].silent_shva() && units[i + 1].letter != 'ר' {
+            units.remove(i);
+            units[i].marks.push(DAGESH);
+        }
+    }
+
+    // a last ת or נ of the root merges with the same letter of an ending
+    let mut i = 0;
+    while i + 1 < units.len() {
+        if units[i].slot == Some(2)
+            && units[i + 1].slot.is_none()
+            && units[i].letter == units[i + 1].letter
+            && matches!(units[i].letter, 'ת' | 'נ')
+            && units[i].silent_shva()
+        {
+            units.remove(i);
+            units[i].marks.push(DAGESH);
+        }
+        i += 1;
+    }
+
+    for i in 0..units.len() {
+        if "בגדכפת".contains(units[i].letter)
+            && !units[i].marks.contains(&DAGESH)
+            && (i == 0 || units[i - 1].silent_shva())
+        {
+            units[i].marks.push(DAGESH);
+        }
+    }
+
+    if let Some(last) = units.last_mut() {
+        if let Some(final_form) = to_final_form(last.letter) {
+            last.letter = final_form;
+            if final_form == 'ך' && last.marks.iter().all(|m| !m.is_hbr_point_vowel()) {
+                last.marks.push(SHVA);
+            }
+        }
+    }
+
+    let mut text = String::new();
+    for mut unit in units {
+        unit.marks.sort_by_key(|&m| combining_class(m));
+        unit.marks.dedup();
+        text.push(unit.letter);
+        text.extend(unit.marks);
+    }
+    text
+}
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+
+    fn canonical(text: &str) -> String {
+        repair_hbr(text, &RepairOptions::default()).0.into_owned()
+    }
+
+    fn form(root: &str, binyan: Binyan, form: VerbForm, pgn: &str) -> String {
+        let paradigm = conjugate_hbr(root, binyan).unwrap();
+        let person = match pgn.chars().next() {
+            Some('1') => Some(Person::First),
+            Some('2') => Some(Person::Second),
+            Some('3') => Some(Person::Third),
+            _ => None,
+        };
+        let gender = if pgn.contains('m') {
+            Some(Gender::Masculine)
+        } else if pgn.contains('f') {
+            Some(Gender::Feminine)
+        } else if pgn.contains('c') {
+            Some(Gender::Common)
+        } else {
+            None
+        };
+        let number = if pgn.ends_with('s') {
+            Some(Number::Singular)
+        } else if pgn.ends_with('p') {
+            Some(Number::Plural)
+        } else {
+            None
+        };
+        paradigm
+            .get(form, person, gender, number)
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn conjugate_qal() {
+        let qal = |f, pgn| form("שמר", Binyan::Qal, f, pgn);
+        assert_eq!(qal(VerbForm::Perfect, "3ms"), canonical("שָׁמַר"));
+        assert_eq!(qal(VerbForm::Perfect, "2fs"), canonical("שָׁמַרְתְּ"));
+        assert_eq!(qal(VerbForm::Perfect, "2mp"), canonical("שְׁמַרְתֶּם"));
+        assert_eq!(qal(VerbForm::Imperfect, "3ms"), canonical("יִשְׁמֹר"));
+        assert_eq!(qal(VerbForm::Imperfect, "2fs"), canonical("תִּשְׁמְרִי"));
+        assert_eq!(qal(VerbForm::Imperative, "2fs"), canonical("שִׁמְרִי"));
+        assert_eq!(qal(VerbForm::Participle, "fs"), canonical("שׁוֹמֶרֶת"));
+        assert_eq!(qal(VerbForm::PassiveParticiple, "ms"), canonical("שָׁמוּר"));
+        assert_eq!(qal(VerbForm::InfinitiveAbsolute, ""), canonical("שָׁמוֹר"));
+
+        let qal = |f, pgn| form("כתב", Binyan::Qal, f, pgn);
+        assert_eq!(qal(VerbForm::Perfect, "1cs"), canonical("כָּתַבְתִּי"));
+        assert_eq!(qal(VerbForm::Imperfect, "3ms"), canonical("יִכְתֹּב"));
+        assert_eq!(qal(VerbForm::Imperfect, "2mp"), canonical("תִּכְתְּבוּ"));
+        assert_eq!(qal(VerbForm::Imperative, "2ms"), canonical("כְּתֹב"));
+
+        assert_eq!(
+            form("כרת", Binyan::Qal, VerbForm::Perfect, "2ms"),
+            canonical("כָּרַתָּ")
+        );
+        assert_eq!(
+            form("שכן", Binyan::Qal, VerbForm::Perfect, "1cp"),
+            canonical("שָׁכַנּוּ")
+        );
+        assert_eq!(
+            form("מלך", Binyan::Qal, VerbForm::Perfect, "3ms"),
+            canonical("מָלַךְ")
+        );
+    }
+
+    #[test]
+    fn conjugate_derived_binyanim() {
+        let get = |root, binyan, f, pgn| form(root, binyan, f, pgn);
+        assert_eq!(
+            get("כתב", Binyan::Niphal, VerbForm::Perfect, "3ms"),
+            canonical("נִכְתַּב")
+        );
+        assert_eq!(
+            get("כתב", Binyan::Niphal, VerbForm::Imperfect, "3ms"),
+            canonical("יִכָּתֵב")
+        );
+        assert_eq!(
+            get("דבר", Binyan::Piel, VerbForm::Perfect, "3ms"),
+            canonical("דִּבֵּר")
+        );
+        assert_eq!(
+            get("דבר", Binyan::Piel, VerbForm::Imperfect, "3ms"),
+            canonical("יְדַבֵּר")
+        );
+        assert_eq!(
+            get("דבר", Binyan::Piel, VerbForm::Participle, "ms"),
+            canonical("מְדַבֵּר")
+        );
+        assert_eq!(
+            get("כתב", Binyan::Hiphil, VerbForm::Perfect, "3ms"),
+            canonical("הִכְתִּיב")
+        );
+        assert_eq!(
+            get("כתב", Binyan::Hiphil, VerbForm::Imperfect, "3ms"),
+            canonical("יַכְתִּיב")
+        );
+        assert_eq!(
+            get("לבש", Binyan::Hitpael, VerbForm::Perfect, "3ms"),
+            canonical("הִתְלַבֵּשׁ")
+        );
+        assert_eq!(
+            get("שמש", Binyan::Hitpael, VerbForm::Perfect, "3ms"),
+            canonical("הִשְׁתַּמֵּשׁ")
+        );
+        assert_eq!(
+            get("צלם", Binyan::Hitpael, VerbForm::Perfect, "3ms"),
+            canonical("הִצְטַלֵּם")
+        );
+        assert_eq!(
+            get("זקן", Binyan::Hitpael, VerbForm::Perfect, "3ms"),
+            canonical("הִזְדַּקֵּן")
+        );
+        assert_eq!(
+            get("דבר", Binyan::Hitpael, VerbForm::Imperfect, "3ms"),
+            canonical("יִדַּבֵּר")
+        );
+        assert_eq!(
+            get("שׂפט", Binyan::Piel, VerbForm::Perfect, "3ms"),
+            canonical("שִׂפֵּט")
+        );
+    }
+
+    #[test]
+    fn conjugate_initial_nun() {
+        let get = |binyan, f, pgn| form("נפל", binyan, f, pgn);
+        assert_eq!(
+            get(Binyan::Niphal, VerbForm::Perfect, "3ms"),
+            canonical("נִפַּל")
+        );
+        assert_eq!(
+            get(Binyan::Niphal, VerbForm::Imperfect, "3ms"),
+            canonical("יִנָּפֵל")
+        );
+        assert_eq!(
+            get(Binyan::Piel, VerbForm::Perfect, "3ms"),
+            canonical("נִפֵּל")
+        );
+        assert_eq!(
+            get(Binyan::Hiphil, VerbForm::Perfect, "3ms"),
+            canonical("הִפִּיל")
+        );
+        assert_eq!(
+            get(Binyan::Hiphil, VerbForm::Participle, "ms"),
+            canonical("מַפִּיל")
+        );
+        assert_eq!(
+            get(Binyan::Hophal, VerbForm::Perfect, "3ms"),
+            canonical("הֻפַּל")
+        );
+        assert_eq!(
+            get(Binyan::Hitpael, VerbForm::Perfect, "3ms"),
+            canonical("הִתְנַפֵּל")
+        );
+        assert_eq!(
+            form("נשק", Binyan::Hiphil, VerbForm::Imperfect, "1cs"),
+            canonical("אַשִּׁיק")
+        );
+    }
+
+    #[test]
+    fn conjugate_all_forms_validate() {
+        let binyanim = [
+            Binyan::Qal,
+            Binyan::Niphal,
+            Binyan::Piel,
+            Binyan::Pual,
+            Binyan::Hiphil,
+            Binyan::Hophal,
+            Binyan::Hitpael,
+        ];
+        for root in [
+            "כתב", "שמר", "דבר", "סגר", "מלך", "שׂפט", "שמש", "צלם", "זקן", "שכן", "גנב", "נפל",
+            "נשק", "נגב",
+        ] {
+            for binyan in binyanim {
+                if root.starts_with('נ') && binyan == Binyan::Qal {
+                    continue;
+                }
+                let paradigm = conjugate_hbr(root, binyan).unwrap();
+                for form in &paradigm.forms {
+                    assert!(validate_hbr(&form.text).is_empty(), "{}", form.text);
+                    assert_eq!(form.text, canonical(&form.text));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn conjugate_unsupported_roots() {
+        use ConjugationError::*;
+        assert_eq!(conjugate_hbr("כת", Binyan::Qal), Err(NotTriliteral(2)));
+        assert_eq!(
+            conjugate_hbr("קום", Binyan::Qal),
+            Err(WeakRoot(RootWeakness::MiddleVavYod))
+        );
+        assert_eq!(
+            conjugate_hbr("ב־נ־ה", Binyan::Piel),
+            Err(WeakRoot(RootWeakness::FinalHe))
+        );
+        assert_eq!(conjugate_hbr("גלי", Binyan::Qal), Err(FinalVavYod('י')));
+        assert_eq!(conjugate_hbr("שלו", Binyan::Qal), Err(FinalVavYod('ו')));
+        assert_eq!(
+            conjugate_hbr("ישב", Binyan::Qal),
+            Err(WeakRoot(RootWeakness::InitialYod))
+        );
+        assert_eq!(
+            conjugate_hbr("נפל", Binyan::Qal),
+            Err(WeakRoot(RootWeakness::InitialNun))
+        );
+        assert_eq!(
+            conjugate_hbr("נרד", Binyan::Hiphil),
+            Err(WeakRoot(RootWeakness::InitialNun))
+        );
+        for binyan in [Binyan::Qal, Binyan::Niphal, Binyan::Hiphil] {
+            assert_eq!(conjugate_hbr("נתן", binyan), Err(IrregularRoot));
+        }
+        assert_eq!(
+            conjugate_hbr("סבב", Binyan::Qal),
+            Err(WeakRoot(RootWeakness::Geminate))
+        );
+        assert_eq!(conjugate_hbr("שאל", Binyan::Qal), Err(GutturalRoot('א')));
+        assert!(conjugate_hbr("ברך", Binyan::Qal).is_ok());
+        assert_eq!(conjugate_hbr("ברך", Binyan::Piel), Err(GutturalRoot('ר')));
+        assert_eq!(
+            ConjugationError::GutturalRoot('ע').to_string(),
+            "roots with the guttural ע are not supported"
+        );
+    }
+}
@@ -0,0 +1,354 @@
+pub mod unicode_block_hebrew {
+    use crate::encoding::{table, REPLACEMENT_BYTE};
+    use crate::{logical_to_visual, visual_to_logical};
+    use crate::{remove_hbr_accent, remove_hbr_mark, remove_hbr_point};
+    use std::error::Error;
+    use std::fmt;
+
+    /// A legacy single-byte encoding for Hebrew text.
+    ///
+    /// ISO-8859-8 and CP862 data is often stored in visual order; the byte mapping is the same
+    /// for visual and logical order, [`decode_hbr_visual`] and [`encode_hbr_visual`] also reorder the lines.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum HebrewEncoding {
+        /// Windows-1255, including the points at 0xC0..0xD2
+        Windows1255,
+        /// ISO-8859-8 (and ISO-8859-8-I), consonants only
+        Iso8859_8,
+        /// DOS code page 862, consonants only
+        Cp862,
+    }
+
+    /// A character that cannot be represented in the requested encoding.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct UnmappableCharacter {
+        /// The byte offset of the character in the given string
+        pub position: usize,
+        pub character: char,
+    }
+
+    /// The error returned when a string contains characters that cannot be encoded.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct EncodeError {
+        pub encoding: HebrewEncoding,
+        pub unmappable: Vec<UnmappableCharacter>,
+    }
+
+    impl fmt::Display for EncodeError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "{} character(s) cannot be encoded in {:?}",
+                self.unmappable.len(),
+                self.encoding
+            )?;
+            if let Some(first) = self.unmappable.first() {
+                write!(
+                    f,
+                    ", the first is U+{:04X} at byte {}",
+                    u32::from(first.character),
+                    first.position
+                )?;
+            }
+            Ok(())
+        }
+    }
+
+    impl Error for EncodeError {}
+
+    /// Decodes bytes in a legacy Hebrew encoding into a string.
+    ///
+    /// Bytes that are undefined in the encoding become U+FFFD REPLACEMENT CHARACTER.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{decode_hbr, HebrewEncoding};
+    ///
+    /// let bytes = [0xF9, 0xC8, 0xD1, 0xEC, 0xC9, 0xE5, 0xED];
+    /// let decoded = decode_hbr(&bytes, HebrewEncoding::Windows1255);
+    /// assert_eq!(decoded, "\u{05E9}\u{05B8}\u{05C1}\u{05DC}\u{05B9}\u{05D5}\u{05DD}");
+    /// ```
+    pub fn decode_hbr(bytes: &[u8], encoding: HebrewEncoding) -> String {
+        let table = table(encoding);
+        bytes
+            .iter()
+            .map(|&b| match b {
+                0x00..=0x7F => char::from(b),
+                _ => match table[usize::from(b - 0x80)] {
+                    0 => '\u{FFFD}',
+                    c => char::from_u32(u32::from(c)).unwrap_or('\u{FFFD}'),
+                },
+            })
+            .collect()
+    }
+
+    /// Decodes bytes in visual order, such as ISO-8859-8 (not ISO-8859-8-I), into a string in logical order.
+    ///
+    /// Every line is decoded with [`decode_hbr`] and reordered with [`crate::visual_to_logical`].
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{decode_hbr_visual, HebrewEncoding};
+    ///
+    /// // "abc םולש" as displayed on a terminal without bidirectional support
+    /// let bytes = [b'a', b'b', b'c', b' ', 0xED, 0xE5, 0xEC, 0xF9];
+    /// assert_eq!(decode_hbr_visual(&bytes, HebrewEncoding::Iso8859_8), "שלום abc");
+    /// ```
+    pub fn decode_hbr_visual(bytes: &[u8], encoding: HebrewEncoding) -> String {
+        visual_to_logical(&decode_hbr(bytes, encoding))
+    }
+
+    /// Encodes a string in logical order into a legacy Hebrew encoding in visual order.
+    ///
+    /// Every line is reordered with [`crate::logical_to_visual`] without wrapping and encoded with [`encode_hbr`];
+    /// the positions of unmappable characters are byte offsets in the reordered string.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{encode_hbr_visual, HebrewEncoding};
+    ///
+    /// let encoded = encode_hbr_visual("שלום abc", HebrewEncoding::Iso8859_8).unwrap();
+    /// assert_eq!(encoded, vec![b'a', b'b', b'c', b' ', 0xED, 0xE5, 0xEC, 0xF9]);
+    /// ```
+    pub fn encode_hbr_visual(text: &str, encoding: HebrewEncoding) -> Result<Vec<u8>, EncodeError> {
+        encode_hbr(&logical_to_visual(text, 0), encoding)
+    }
+
+    /// Encodes a string into a legacy Hebrew encoding.
+    ///
+    /// All characters that cannot be represented are reported in the error.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{encode_hbr, HebrewEncoding};
+    ///
+    /// let encoded = encode_hbr("בָּרָא", HebrewEncoding::Windows1255).unwrap();
+    /// assert_eq!(encoded, vec![0xE1, 0xC8, 0xCC, 0xF8, 0xC8, 0xE0]);
+    ///
+    /// let error = encode_hbr("בָּ֑", HebrewEncoding::Windows1255).unwrap_err();
+    /// assert_eq!(error.unmappable[0].character, '\u{0591}');
+    /// ```
+    pub fn encode_hbr(text: &str, encoding: HebrewEncoding) -> Result<Vec<u8>, EncodeError> {
+        let mut encoded = Vec::with_capacity(text.len());
+        let mut unmappable = Vec::new();
+        for (position, character) in text.char_indices() {
+            match encode_char(character, encoding) {
+                Some(b) => encoded.push(b),
+                None => unmappable.push(UnmappableCharacter {
+                    position,
+                    character,
+                }),
+            }
+        }
+        if unmappable.is_empty() {
+            Ok(encoded)
+        } else {
+            Err(EncodeError {
+                encoding,
+                unmappable,
+            })
+        }
+    }
+
+    /// Encodes a string into a legacy Hebrew encoding, dropping what the encoding cannot represent.
+    ///
+    /// Accents are removed first, and for ISO-8859-8 and CP862 also points and marks.
+    /// Any other character that cannot be represented becomes a question mark.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{decode_hbr, encode_hbr_lossy, HebrewEncoding};
+    ///
+    /// let encoded = encode_hbr_lossy("בְּרֵאשִׁ֖ית ✓", HebrewEncoding::Iso8859_8);
+    /// assert_eq!(decode_hbr(&encoded, HebrewEncoding::Iso8859_8), "בראשית ?");
+    /// ```
+    pub fn encode_hbr_lossy(text: &str, encoding: HebrewEncoding) -> Vec<u8> {
+        let text = remove_hbr_accent(text);
+        let text = match encoding {
+            HebrewEncoding::Windows1255 => text,
+            _ => remove_hbr_mark(&remove_hbr_point(&text))
+                .into_owned()
+                .into(),
+        };
+        text.chars()
+            .map(|c| encode_char(c, encoding).unwrap_or(REPLACEMENT_BYTE))
+            .collect()
+    }
+
+    fn encode_char(c: char, encoding: HebrewEncoding) -> Option<u8> {
+        if c.is_ascii() {
+            return Some(c as u8);
+        }
+        let code = u16::try_from(u32::from(c)).ok()?;
+        table(encoding)
+            .iter()
+            .position(|&t| t == code)
+            .map(|i| 0x80 + i as u8)
+    }
+}
+
+use self::unicode_block_hebrew::HebrewEncoding;
+
+pub(crate) const REPLACEMENT_BYTE: u8 = b'?';
+
+/// Returns the characters of the bytes 0x80..0xFF, 0 marks an undefined byte.
+pub(crate) fn table(encoding: HebrewEncoding) -> &'static [u16; 128] {
+    match encoding {
+        HebrewEncoding::Windows1255 => &WINDOWS_1255,
+        HebrewEncoding::Iso8859_8 => &ISO_8859_8,
+        HebrewEncoding::Cp862 => &CP862,
+    }
+}
+
+#[rustfmt::skip]
+const WINDOWS_1255: [u16; 128] = [
+    // 0x80
+    0x20AC, 0, 0x201A, 0x0192, 0x201E, 0x2026, 0x2020, 0x2021,
+    0x02C6, 0x2030, 0, 0x2039, 0, 0, 0, 0,
+    // 0x90
+    0, 0x2018, 0x2019, 0x201C, 0x201D, 0x2022, 0x2013, 0x2014,
+    0x02DC, 0x2122, 0, 0x203A, 0, 0, 0, 0,
+    // 0xA0
+    0x00A0, 0x00A1, 0x00A2, 0x00A3, 0x20AA, 0x00A5, 0x00A6, 0x00A7,
+    0x00A8, 0x00A9, 0x00D7, 0x00AB, 0x00AC, 0x00AD, 0x00AE, 0x00AF,
+    // 0xB0
+    0x00B0, 0x00B1, 0x00B2, 0x00B3, 0x00B4, 0x00B5, 0x00B6, 0x00B7,
+    0x00B8, 0x00B9, 0x00F7, 0x00BB, 0x00BC, 0x00BD, 0x00BE, 0x00BF,
+    // 0xC0
+    0x05B0, 0x05B1, 0x05B2, 0x05B3, 0x05B4, 0x05B5, 0x05B6, 0x05B7,
+    0x05B8, 0x05B9, 0x05BA, 0x05BB, 0x05BC, 0x05BD, 0x05BE, 0x05BF,
+    // 0xD0
+    0x05C0, 0x05C1, 0x05C2, 0x05C3, 0x05F0, 0x05F1, 0x05F2, 0x05F3,
+    0x05F4, 0, 0, 0, 0, 0, 0, 0,
+    // 0xE0
+    0x05D0, 0x05D1, 0x05D2, 0x05D3, 0x05D4, 0x05D5, 0x05D6, 0x05D7,
+    0x05D8, 0x05D9, 0x05DA, 0x05DB, 0x05DC, 0x05DD, 0x05DE, 0x05DF,
+    // 0xF0
+    0x05E0, 0x05E1, 0x05E2, 0x05E3, 0x05E4, 0x05E5, 0x05E6, 0x05E7,
+    0x05E8, 0x05E9, 0x05EA, 0, 0, 0x200E, 0x200F, 0,
+];
+
+#[rustfmt::skip]
+const ISO_8859_8: [u16; 128] = [
+    // 0x80
+    0x0080, 0x0081, 0x0082, 0x0083, 0x0084, 0x0085, 0x0086, 0x0087,
+    0x0088, 0x0089, 0x008A, 0x008B, 0x008C, 0x008D, 0x008E, 0x008F,
+    // 0x90
+    0x0090, 0x0091, 0x0092, 0x0093, 0x0094, 0x0095, 0x0096, 0x0097,
+    0x0098, 0x0099, 0x009A, 0x009B, 0x009C, 0x009D, 0x009E, 0x009F,
+    // 0xA0
+    0x00A0, 0, 0x00A2, 0x00A3, 0x00A4, 0x00A5, 0x00A6, 0x00A7,
+    0x00A8, 0x00A9, 0x00D7, 0x00AB, 0x00AC, 0x00AD, 0x00AE, 0x00AF,
+    // 0xB0
+    0x00B0, 0x00B1, 0x00B2, 0x00B3, 0x00B4, 0x00B5, 0x00B6, 0x00B7,
+    0x00B8, 0x00B9, 0x00F7, 0x00BB, 0x00BC, 0x00BD, 0x00BE, 0,
+    // 0xC0
+    0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0,
+    // 0xD0
+    0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0x2017,
+    // 0xE0
+    0x05D0, 0x05D1, 0x05D2, 0x05D3, 0x05D4, 0x05D5, 0x05D6, 0x05D7,
+    0x05D8, 0x05D9, 0x05DA, 0x05DB, 0x05DC, 0x05DD, 0x05DE, 0x05DF,
+    // 0xF0
+    0x05E0, 0x05E1, 0x05E2, 0x05E3, 0x05E4, 0x05E5, 0x05E6, 0x05E7,
+    0x05E8, 0x05E9, 0x05EA, 0, 0, 0x200E, 0x200F, 0,
+];
+
+#[rustfmt::skip]
+const CP862: [u16; 128] = [
+    // 0x80
+    0x05D0, 0x05D1, 0x05D2, 0x05D3, 0x05D4, 0x05D5, 0x05D6, 0x05D7,
+    0x05D8, 0x05D9, 0x05DA, 0x05DB, 0x05DC, 0x05DD, 0x05DE, 0x05DF,
+    // 0x90
+    0x05E0, 0x05E1, 0x05E2, 0x05E3, 0x05E4, 0x05E5, 0x05E6, 0x05E7,
+    0x05E8, 0x05E9, 0x05EA, 0x00A2, 0x00A3, 0x00A5, 0x20A7, 0x0192,
+    // 0xA0
+    0x00E1, 0x00ED, 0x00F3, 0x00FA, 0x00F1, 0x00D1, 0x00AA, 0x00BA,
+    0x00BF, 0x2310, 0x00AC, 0x00BD, 0x00BC, 0x00A1, 0x00AB, 0x00BB,
+    // 0xB0
+    0x2591, 0x2592, 0x2593, 0x2502, 0x2524, 0x2561, 0x2562, 0x2556,
+    0x2555, 0x2563, 0x2551, 0x2557, 0x255D, 0x255C, 0x255B, 0x2510,
+    // 0xC0
+    0x2514, 0x2534, 0x252C, 0x251C, 0x2500, 0x253C, 0x255E, 0x255F,
+    0x255A, 0x2554, 0x2569, 0x2566, 0x2560, 0x2550, 0x256C, 0x2567,
+    // 0xD0
+    0x2568, 0x2564, 0x2565, 0x2559, 0x2558, 0x2552, 0x2553, 0x256B,
+    0x256A, 0x2518, 0x250C, 0x2588, 0x2584, 0x258C, 0x2590, 0x2580,
+    // 0xE0
+    0x03B1, 0x00DF, 0x0393, 0x03C0, 0x03A3, 0x03C3, 0x00B5, 0x03C4,
+    0x03A6, 0x0398, 0x03A9, 0x03B4, 0x221E, 0x03C6, 0x03B5, 0x2229,
+    // 0xF0
+    0x2261, 0x00B1, 0x2265, 0x2264, 0x2320, 0x2321, 0x00F7, 0x2248,
+    0x00B0, 0x2219, 0x00B7, 0x221A, 0x207F, 0x00B2, 0x25A0, 0x00A0,
+];
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+
+    #[test]
+    fn windows_1255_roundtrip_with_points() {
+        let text = "וַיֹּאמֶר אֱלֹהִים׃ ₪";
+        let encoded = encode_hbr(text, HebrewEncoding::Windows1255).unwrap();
+        assert_eq!(encoded.len(), text.chars().count());
+        assert_eq!(decode_hbr(&encoded, HebrewEncoding::Windows1255), text);
+    }
+
+    #[test]
+    fn iso_8859_8_and_cp862_letters() {
+        let text = "אבגדהוזחטיךכלםמןנסעףפץצקרשת";
+        for encoding in [HebrewEncoding::Iso8859_8, HebrewEncoding::Cp862] {
+            let encoded = encode_hbr(text, encoding).unwrap();
+            assert_eq!(decode_hbr(&encoded, encoding), text);
+        }
+        let encoded = encode_hbr("את", HebrewEncoding::Cp862).unwrap();
+        assert_eq!(encoded, vec![0x80, 0x9A]);
+        let encoded = encode_hbr("את", HebrewEncoding::Iso8859_8).unwrap();
+        assert_eq!(encoded, vec![0xE0, 0xFA]);
+    }
+
+    #[test]
+    fn iso_8859_8_visual_order() {
+        // "פרק 12 (סוף)" on two lines, as stored by a visual ISO-8859-8 system
+        let mut visual = vec![b'1', b'2', b' ', 0xF7, 0xF8, 0xF4, b'\n'];
+        visual.extend([b'(', 0xF3, 0xE5, 0xF1, b')']);
+        let logical = "פרק 12\n(סוף)";
+        assert_eq!(
+            decode_hbr_visual(&visual, HebrewEncoding::Iso8859_8),
+            logical
+        );
+        assert_eq!(
+            encode_hbr_visual(logical, HebrewEncoding::Iso8859_8).unwrap(),
+            visual
+        );
+        assert_eq!(
+            decode_hbr(&visual, HebrewEncoding::Iso8859_8),
+            "12 קרפ\n(ףוס)"
+        );
+    }
+
+    #[test]
+    fn encode_reports_all_unmappable_characters() {
+        let error = encode_hbr("בָּ֑רָ֥א", HebrewEncoding::Windows1255).unwrap_err();
+        let positions: Vec<usize> = error.unmappable.iter().map(|u| u.position).collect();
+        assert_eq!(positions, vec![6, 12]);
+        assert_eq!(
+            error.to_string(),
+            "2 character(s) cannot be encoded in Windows1255, the first is U+0591 at byte 6"
+        );
+    }
+
+    #[test]
+    fn encode_lossy_keeps_points_in_windows_1255() {
+        let encoded = encode_hbr_lossy("בָּ֑רָ֥א", HebrewEncoding::Windows1255);
+        assert_eq!(decode_hbr(&encoded, HebrewEncoding::Windows1255), "בָּרָא");
+    }
+
+    #[test]
+    fn decode_undefined_bytes() {
+        let decoded = decode_hbr(&[0x41, 0xFF, 0xD9], HebrewEncoding::Windows1255);
+        assert_eq!(decoded, "A\u{FFFD}\u{FFFD}");
+    }
+}
@@ -0,0 +1,154 @@
+pub mod unicode_block_hebrew {
+    use crate::final_forms::{to_final_form, to_normal_form};
+    use crate::get_hbr_clusters;
+    use hebrew_unicode_script::HebrewUnicodeScript;
+    use std::borrow::Cow;
+
+    /// Puts kaf, mem, nun, pe and tsadi in their final form at the end of a word, and in their normal form elsewhere.
+    ///
+    /// A word ends before a space, maqaf, punctuation or non-Hebrew character.
+    /// A letter followed by a geresh or gershayim is part of an abbreviation (צה״ל, פרופ׳) and keeps its normal form,
+    /// except for the foreign sound ץ׳ at the end of a word. Single-letter words are left untouched.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::to_final_forms;
+    ///
+    /// assert_eq!(to_final_forms("שלומ מןצב").as_ref(), "שלום מנצב");
+    /// assert_eq!(to_final_forms("כל־עמ").as_ref(), "כל־עם");
+    /// assert_eq!(to_final_forms("רמב״מ פרופ׳").as_ref(), "רמב״ם פרופ׳");
+    /// ```
+    pub fn to_final_forms(text: &str) -> Cow<'_, str> {
+        let clusters = get_hbr_clusters(text);
+        let mut result = String::new();
+        let mut copied = 0;
+        for (i, cluster) in clusters.iter().enumerate() {
+            let base = match cluster.base() {
+                Some(c) if to_final_form(to_normal_form(c)).is_some() => c,
+                _ => continue,
+            };
+            let next = clusters.get(i + 1).and_then(|n| n.base());
+            let after_next = clusters.get(i + 2).and_then(|n| n.base());
+            let is_letter = |c: Option<char>| c.map_or(false, |c| c.is_hbr_consonant());
+            let word_end = match next {
+                Some(c) if c.is_hbr_consonant() => false,
+                Some('\u{05F3}') | Some('\'') if is_letter(after_next) => false,
+                Some('\u{05F3}') | Some('\'') => matches!(base, 'צ' | 'ץ'),
+                Some('\u{05F4}') | Some('"') => !is_letter(after_next),
+                _ => true,
+            };
+            let previous = i.checked_sub(1).and_then(|p| clusters[p].base());
+            let previous_is_letter = match previous {
+                Some('\u{05F4}') | Some('"') => i > 1 && is_letter(clusters[i - 2].base()),
+                p => is_letter(p),
+            };
+            let replacement = if !word_end {
+                to_normal_form(base)
+            } else if previous_is_letter || next == Some('\u{05F3}') || next == Some('\'') {
+                to_final_form(base).unwrap_or(base)
+            } else {
+                base
+            };
+            if replacement != base {
+                result.push_str(&text[copied..cluster.span.start]);
+                result.push(replacement);
+                copied = cluster.span.start + base.len_utf8();
+            }
+        }
+        if copied == 0 {
+            return Cow::Borrowed(text);
+        }
+        result.push_str(&text[copied..]);
+        Cow::Owned(result)
+    }
+
+    /// Replaces the five final letters by their normal counterparts, for example to build search keys.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::fold_final_forms;
+    ///
+    /// let test_str = "ךםןףץ";
+    /// let test_str_folded = fold_final_forms(test_str);
+    /// assert_eq!(test_str_folded.as_ref(), "כמנפצ");
+    /// ```
+    pub fn fold_final_forms(text: &str) -> Cow<'_, str> {
+        if !text.chars().any(|c| c.is_hbr_consonant_final()) {
+            return Cow::Borrowed(text);
+        }
+        text.chars().map(to_normal_form).collect()
+    }
+}
+
+/// Returns the normal form of a final letter, other characters are returned unchanged.
+pub(crate) fn to_normal_form(c: char) -> char {
+    match c {
+        'ך' => 'כ',
+        'ם' => 'מ',
+        'ן' => 'נ',
+        'ף' => 'פ',
+        'ץ' => 'צ',
+        _ => c,
+    }
+}
+
+/// Returns the final form of kaf, mem, nun, pe and tsadi.
+pub(crate) fn to_final_form(c: char) -> Option<char> {
+    match c {
+        'כ' => Some('ך'),
+        'מ' => Some('ם'),
+        'נ' => Some('ן'),
+        'פ' => Some('ף'),
+        'צ' => Some('ץ'),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+
+    #[test]
+    fn final_forms_word_end_and_medial() {
+        assert_eq!(to_final_forms("מלכ ןפש").as_ref(), "מלך נפש");
+        assert_eq!(to_final_forms("השמימ.").as_ref(), "השמים.");
+        assert_eq!(to_final_forms("abc מנ").as_ref(), "abc מן");
+    }
+
+    #[test]
+    fn final_forms_keep_points() {
+        assert_eq!(to_final_forms("לֶכְ").as_ref(), "לֶךְ");
+        assert_eq!(to_final_forms("הַשָּׁמַיִםוְ").as_ref(), "הַשָּׁמַיִמוְ");
+    }
+
+    #[test]
+    fn final_forms_maqaf_and_abbreviations() {
+        assert_eq!(to_final_forms("עמ־ישראל").as_ref(), "עם־ישראל");
+        assert_eq!(to_final_forms("צה\"ל").as_ref(), "צה\"ל");
+        assert_eq!(to_final_forms("סנדוויצ׳").as_ref(), "סנדוויץ׳");
+        assert_eq!(to_final_forms("ץ׳יפס").as_ref(), "צ׳יפס");
+        assert_eq!(to_final_forms("עמ׳ 5").as_ref(), "עמ׳ 5");
+    }
+
+    #[test]
+    fn final_forms_single_letter_unchanged() {
+        assert_eq!(to_final_forms("כ מ").as_ref(), "כ מ");
+    }
+
+    #[test]
+    fn final_forms_unchanged_is_borrowed() {
+        assert!(matches!(
+            to_final_forms("שלום"),
+            std::borrow::Cow::Borrowed(_)
+        ));
+        assert!(matches!(
+            fold_final_forms("abc"),
+            std::borrow::Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn fold_final_forms_mixed() {
+        assert_eq!(fold_final_forms("מֶלֶךְ שָׁלוֹם").as_ref(), "מֶלֶכְ שָׁלוֹמ");
+    }
+}
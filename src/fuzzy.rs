@@ -0,0 +1,214 @@
+pub mod unicode_block_hebrew {
+    use crate::fuzzy::{indel_cost, skeleton, substitution_cost};
+    use std::cmp::Ordering;
+
+    /// A candidate ranked by [`rank_hbr_fuzzy`].
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct FuzzyMatch<'a> {
+        /// The candidate as given
+        pub candidate: &'a str,
+        /// The position of the candidate in the given candidates
+        pub index: usize,
+        /// The weighted edit distance to the query, see [`hbr_edit_distance`]
+        pub distance: f64,
+        /// The similarity to the query, from 0.0 up to and including 1.0
+        pub score: f64,
+    }
+
+    /// Calculates a weighted edit distance between the consonants of two strings.
+    ///
+    /// Points, accents and marks are ignored, except for the shin and sin dots; final letters count as normal letters.
+    /// Letters that are often confused cost less to substitute than other letters:
+    /// - ט and ת, ב and ו, ש and שׂ, שׂ and ס: 0.25 (mostly one sound)
+    /// - כ, ק and ח; ס and ש; א, ע and ה: 0.5
+    /// - any other substitution: 1.0
+    ///
+    /// Inserting or deleting a mater lectionis (plene versus defective spelling) costs 0.25 for ו and י
+    /// and 0.5 for א and ה; any other insertion or deletion costs 1.0.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::hbr_edit_distance;
+    ///
+    /// assert_eq!(hbr_edit_distance("שָׁלוֹם", "שלום"), 0.0);
+    /// assert_eq!(hbr_edit_distance("שלמה", "שלומה"), 0.25);
+    /// assert_eq!(hbr_edit_distance("טוביה", "תוביה"), 0.25);
+    /// assert_eq!(hbr_edit_distance("כהן", "קהן"), 0.5);
+    /// assert_eq!(hbr_edit_distance("כהן", "לוי"), 2.75);
+    /// ```
+    pub fn hbr_edit_distance(a: &str, b: &str) -> f64 {
+        distance(&skeleton(a), &skeleton(b))
+    }
+
+    /// Calculates the similarity of two strings from their [`hbr_edit_distance`],
+    /// from 0.0 (nothing in common) up to and including 1.0 (equal consonants).
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::hbr_similarity;
+    ///
+    /// assert_eq!(hbr_similarity("יוסף", "יוסף"), 1.0);
+    /// assert_eq!(hbr_similarity("יוסף", "יסף"), 0.9375);
+    /// ```
+    pub fn hbr_similarity(a: &str, b: &str) -> f64 {
+        let (a, b) = (skeleton(a), skeleton(b));
+        similarity(&a, &b, distance(&a, &b))
+    }
+
+    /// Ranks the candidates by their weighted edit distance to the query, the closest first.
+    ///
+    /// Candidates with an equal distance keep their original order.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::rank_hbr_fuzzy;
+    ///
+    /// let names = ["אברהם", "יצחק", "איצחק", "יעקב", "יצחאק"];
+    /// let ranked = rank_hbr_fuzzy("יצחק", names.iter().copied());
+    /// let order: Vec<&str> = ranked.iter().map(|m| m.candidate).collect();
+    /// assert_eq!(order, vec!["יצחק", "איצחק", "יצחאק", "יעקב", "אברהם"]);
+    /// assert_eq!(ranked[1].index, 2);
+    /// ```
+    pub fn rank_hbr_fuzzy<'a, I>(query: &str, candidates: I) -> Vec<FuzzyMatch<'a>>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let query = skeleton(query);
+        let mut matches: Vec<FuzzyMatch<'a>> = candidates
+            .into_iter()
+            .enumerate()
+            .map(|(index, candidate)| {
+                let letters = skeleton(candidate);
+                let distance = distance(&query, &letters);
+                FuzzyMatch {
+                    candidate,
+                    index,
+                    distance,
+                    score: similarity(&query, &letters, distance),
+                }
+            })
+            .collect();
+        matches.sort_by(|x, y| {
+            x.distance
+                .partial_cmp(&y.distance)
+                .unwrap_or(Ordering::Equal)
+                .then(x.index.cmp(&y.index))
+        });
+        matches
+    }
+
+    /// Weighted Levenshtein distance.
+    fn distance(a: &[char], b: &[char]) -> f64 {
+        let mut previous: Vec<f64> = Vec::with_capacity(b.len() + 1);
+        previous.push(0.0);
+        for &c in b {
+            let last = previous[previous.len() - 1];
+            previous.push(last + indel_cost(c));
+        }
+        let mut current = vec![0.0; b.len() + 1];
+        for &ca in a {
+            current[0] = previous[0] + indel_cost(ca);
+            for (j, &cb) in b.iter().enumerate() {
+                let substitute = previous[j] + substitution_cost(ca, cb);
+                let delete = previous[j + 1] + indel_cost(ca);
+                let insert = current[j] + indel_cost(cb);
+                current[j + 1] = substitute.min(delete).min(insert);
+            }
+            std::mem::swap(&mut previous, &mut current);
+        }
+        previous[b.len()]
+    }
+
+    fn similarity(a: &[char], b: &[char], distance: f64) -> f64 {
+        let longest = a.len().max(b.len());
+        if longest == 0 {
+            return 1.0;
+        }
+        (1.0 - distance / longest as f64).max(0.0)
+    }
+}
+
+use crate::comparison::apf_chars;
+use crate::final_forms::to_normal_form;
+use hebrew_unicode_script::HebrewUnicodeScript;
+
+/// The sin (ש with sin dot) in the skeleton of a string.
+const SIN: char = '\u{FB2B}';
+
+/// Returns the letters and other base characters of a string, with final letters folded
+/// and a shin with sin dot replaced by [`SIN`].
+pub(crate) fn skeleton(text: &str) -> Vec<char> {
+    let mut letters: Vec<char> = Vec::with_capacity(text.len());
+    for c in apf_chars(text) {
+        match c {
+            '\u{05C2}' if letters.last() == Some(&'ש') => {
+                letters.pop();
+                letters.push(SIN);
+            }
+            _ if c.is_hbr_accent()
+                || c.is_hbr_mark()
+                || c.is_hbr_point()
+                || c.is_apf_point_reading_sign() => {}
+            _ => letters.push(to_normal_form(c)),
+        }
+    }
+    letters
+}
+
+/// Returns the cost of substituting one letter by another.
+pub(crate) fn substitution_cost(a: char, b: char) -> f64 {
+    if a == b {
+        return 0.0;
+    }
+    let pair = |x: char, y: char| (a == x && b == y) || (a == y && b == x);
+    if pair('ט', 'ת') || pair('ב', 'ו') || pair('ש', SIN) || pair(SIN, 'ס') {
+        return 0.25;
+    }
+    let group = |letters: &str| letters.contains(a) && letters.contains(b);
+    if group("כקח") || group("סש") || group("אעה") {
+        return 0.5;
+    }
+    1.0
+}
+
+/// Returns the cost of inserting or deleting a letter.
+pub(crate) fn indel_cost(c: char) -> f64 {
+    match c {
+        'ו' | 'י' => 0.25,
+        'א' | 'ה' => 0.5,
+        _ => 1.0,
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+
+    #[test]
+    fn edit_distance_is_symmetric() {
+        let words = ["משה", "מושה", "מֹשֶׁה", "שׂרה", "סרה", "שרה", ""];
+        for a in words.iter() {
+            for b in words.iter() {
+                assert_eq!(hbr_edit_distance(a, b), hbr_edit_distance(b, a));
+            }
+        }
+    }
+
+    #[test]
+    fn edit_distance_sin_and_samekh() {
+        assert_eq!(hbr_edit_distance("שָׂרָה", "סרה"), 0.25);
+        assert_eq!(hbr_edit_distance("שָׁרָה", "סרה"), 0.5);
+        assert_eq!(hbr_edit_distance("\u{FB2B}רה", "שָׂרָה"), 0.0);
+        assert_eq!(hbr_edit_distance("", "יוסף"), 2.5);
+    }
+
+    #[test]
+    fn rank_genealogical_spellings() {
+        let records = ["רבקה", "רבקא", "רבקה", "ריבקה", "רחל", "רבקע"];
+        let ranked = rank_hbr_fuzzy("רִבְקָה", records.iter().copied());
+        let order: Vec<usize> = ranked.iter().map(|m| m.index).collect();
+        assert_eq!(order, vec![0, 2, 3, 1, 5, 4]);
+        assert_eq!(ranked[0].score, 1.0);
+        assert!(ranked[5].score < 0.5);
+    }
+}
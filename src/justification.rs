@@ -0,0 +1,189 @@
+pub mod unicode_block_alphabetic_presentation_form {
+    use crate::get_hbr_clusters;
+    use crate::justification::{to_normal_width, to_wide_letter};
+    use hebrew_unicode_script::HebrewUnicodeScript;
+    use std::borrow::Cow;
+
+    /// Get the width of the given string in letter units.
+    ///
+    /// Every letter, space or other base character counts as one unit, a wide letter (U+FB21..U+FB28) as two.
+    /// Points, accents and marks do not count.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::get_hbr_letter_units;
+    ///
+    /// assert_eq!(get_hbr_letter_units("בְּרֵאשִׁית בָּרָא"), 10);
+    /// assert_eq!(get_hbr_letter_units("\u{FB21}ב"), 3);
+    /// ```
+    pub fn get_hbr_letter_units(text: &str) -> usize {
+        get_hbr_clusters(text)
+            .iter()
+            .filter_map(|cluster| cluster.base())
+            .map(|c| if c.is_apf_consonant_wide() { 2 } else { 1 })
+            .sum()
+    }
+
+    /// Justifies a line of Hebrew text to the given width in letter units by substituting wide letters.
+    ///
+    /// Following scribal practice, letters are widened from the end of the line backwards, one letter per word in each round,
+    /// preferring the letters of אהלתם over ד, כ and ר and the last letters of a word over earlier ones.
+    /// When the line cannot be widened enough, all candidate letters are widened.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{get_hbr_letter_units, justify_hbr_line};
+    ///
+    /// let line = "וַיֹּאמֶר אֱלֹהִים";
+    /// let justified = justify_hbr_line(line, 14);
+    /// assert_eq!(justified.as_ref(), "וַיֹּ\u{FB21}מֶר אֱלֹ\u{FB23}ִי\u{FB26}");
+    /// assert_eq!(get_hbr_letter_units(&justified), 14);
+    /// ```
+    pub fn justify_hbr_line(line: &str, width: usize) -> Cow<'_, str> {
+        let units = get_hbr_letter_units(line);
+        if units >= width {
+            return Cow::Borrowed(line);
+        }
+        let clusters = get_hbr_clusters(line);
+        // (rank within the word, word counted from the end of the line, cluster index)
+        let mut candidates = Vec::new();
+        let mut word = Vec::new();
+        let mut words_from_end = 0;
+        for (i, cluster) in clusters.iter().enumerate().rev() {
+            match cluster.base() {
+                Some(c) if c.is_hbr_consonant() => word.push((i, c)),
+                _ if !word.is_empty() => {
+                    rank_word(&mut word, words_from_end, &mut candidates);
+                    words_from_end += 1;
+                }
+                _ => {}
+            }
+        }
+        rank_word(&mut word, words_from_end, &mut candidates);
+        candidates.sort_unstable();
+
+        let mut widened: Vec<usize> = candidates
+            .iter()
+            .take(width - units)
+            .map(|&(_, _, i)| i)
+            .collect();
+        widened.sort_unstable();
+        let mut justified = String::with_capacity(line.len() + widened.len() * 2);
+        let mut copied = 0;
+        for i in widened {
+            let span = &clusters[i].span;
+            let base = clusters[i].base().unwrap_or_default();
+            justified.push_str(&line[copied..span.start]);
+            justified.push(to_wide_letter(base).unwrap_or(base));
+            copied = span.start + base.len_utf8();
+        }
+        justified.push_str(&line[copied..]);
+        Cow::Owned(justified)
+    }
+
+    /// Ranks the widenable letters of a word, given from its last letter to its first.
+    fn rank_word(
+        word: &mut Vec<(usize, char)>,
+        words_from_end: usize,
+        candidates: &mut Vec<(usize, usize, usize)>,
+    ) {
+        let mut widenable: Vec<(bool, usize)> = word
+            .iter()
+            .filter(|&&(_, c)| to_wide_letter(c).is_some())
+            .map(|&(i, c)| (!matches!(c, 'א' | 'ה' | 'ל' | 'ת' | 'ם'), i))
+            .collect();
+        // a stable sort keeps the letters nearest to the end first
+        widenable.sort_by_key(|&(secondary, _)| secondary);
+        for (rank, (_, i)) in widenable.into_iter().enumerate() {
+            candidates.push((rank, words_from_end, i));
+        }
+        word.clear();
+    }
+
+    /// Replaces the wide letters (U+FB21..U+FB28) by their normal counterparts.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::fold_hbr_wide_letters;
+    ///
+    /// let test_str = "\u{FB21}\u{FB22}\u{FB23}\u{FB24}\u{FB25}\u{FB26}\u{FB27}\u{FB28}";
+    /// let test_str_folded = fold_hbr_wide_letters(test_str);
+    /// assert_eq!(test_str_folded.as_ref(), "אדהכלםרת");
+    /// ```
+    pub fn fold_hbr_wide_letters(text: &str) -> Cow<'_, str> {
+        if !text.chars().any(|c| c.is_apf_consonant_wide()) {
+            return Cow::Borrowed(text);
+        }
+        text.chars().map(to_normal_width).collect()
+    }
+}
+
+/// Returns the wide form of alef, dalet, he, kaf, lamed, final mem, resh and tav.
+pub(crate) fn to_wide_letter(c: char) -> Option<char> {
+    match c {
+        'א' => Some('\u{FB21}'),
+        'ד' => Some('\u{FB22}'),
+        'ה' => Some('\u{FB23}'),
+        'כ' => Some('\u{FB24}'),
+        'ל' => Some('\u{FB25}'),
+        'ם' => Some('\u{FB26}'),
+        'ר' => Some('\u{FB27}'),
+        'ת' => Some('\u{FB28}'),
+        _ => None,
+    }
+}
+
+/// Returns the normal letter of a wide letter, other characters are returned unchanged.
+pub(crate) fn to_normal_width(c: char) -> char {
+    match c {
+        '\u{FB21}' => 'א',
+        '\u{FB22}' => 'ד',
+        '\u{FB23}' => 'ה',
+        '\u{FB24}' => 'כ',
+        '\u{FB25}' => 'ל',
+        '\u{FB26}' => 'ם',
+        '\u{FB27}' => 'ר',
+        '\u{FB28}' => 'ת',
+        _ => c,
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+
+    #[test]
+    fn justify_one_letter_per_word_from_the_end() {
+        let line = "אלה תולדות השמים";
+        assert_eq!(get_hbr_letter_units(line), 16);
+        let justified = justify_hbr_line(line, 19);
+        assert_eq!(justified.as_ref(), "אל\u{FB23} תולדו\u{FB28} השמי\u{FB26}");
+        assert_eq!(get_hbr_letter_units(&justified), 19);
+    }
+
+    #[test]
+    fn justify_second_round_and_limit() {
+        let line = "אל הים";
+        let justified = justify_hbr_line(line, 9);
+        assert_eq!(justified.as_ref(), "א\u{FB25} \u{FB23}י\u{FB26}");
+        let justified = justify_hbr_line(line, 50);
+        assert_eq!(justified.as_ref(), "\u{FB21}\u{FB25} \u{FB23}י\u{FB26}");
+    }
+
+    #[test]
+    fn justify_wide_enough_is_unchanged() {
+        let line = "שלום עולם";
+        assert!(matches!(
+            justify_hbr_line(line, 5),
+            std::borrow::Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn fold_wide_letters_roundtrip() {
+        let line = "וַיֹּאמֶר אֱלֹהִים יְהִי אוֹר";
+        let justified = justify_hbr_line(line, 30);
+        assert_ne!(justified.as_ref(), line);
+        assert_eq!(fold_hbr_wide_letters(&justified).as_ref(), line);
+    }
+}
@@ -0,0 +1,750 @@
+pub mod unicode_block_hebrew {
+    use crate::lexicon::{parse_json, root_key, Json};
+    use crate::{segment_hbr_word, HebrewSearchKey};
+    use std::collections::HashMap;
+    use std::error::Error;
+    use std::fmt;
+    use std::io;
+    use std::path::Path;
+
+    /// A word form of a lexicon with the lemma it belongs to.
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    pub struct LexiconEntry {
+        /// The form as it occurs in text, pointed or not
+        pub form: String,
+        /// The dictionary form of the word
+        pub lemma: String,
+        /// The root letters, if known
+        pub root: Option<String>,
+        /// Free-form tags such as the part of speech
+        pub tags: Vec<String>,
+    }
+
+    /// A source of Hebrew word forms and their lemmas.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{lemmatize_hbr, HebrewLexicon, HebrewSearchKey, LexiconEntry};
+    ///
+    /// struct OneWord;
+    ///
+    /// impl HebrewLexicon for OneWord {
+    ///     fn lookup_form(&self, form: &str) -> Vec<LexiconEntry> {
+    ///         self.lookup_key(&HebrewSearchKey::new(form))
+    ///     }
+    ///     fn lookup_key(&self, key: &HebrewSearchKey) -> Vec<LexiconEntry> {
+    ///         match key.as_str() {
+    ///             "ספרימ" => vec![LexiconEntry {
+    ///                 form: "ספרים".to_string(),
+    ///                 lemma: "ספר".to_string(),
+    ///                 root: None,
+    ///                 tags: Vec::new(),
+    ///             }],
+    ///             _ => Vec::new(),
+    ///         }
+    ///     }
+    ///     fn lookup_root(&self, _root: &str) -> Vec<LexiconEntry> {
+    ///         Vec::new()
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(lemmatize_hbr("וְהַסְּפָרִים", &OneWord)[0].entry.lemma, "ספר");
+    /// ```
+    pub trait HebrewLexicon {
+        /// Returns the entries with exactly the given form.
+        fn lookup_form(&self, form: &str) -> Vec<LexiconEntry>;
+
+        /// Returns the entries whose form has the given [`HebrewSearchKey`], regardless of points and final letters.
+        fn lookup_key(&self, key: &HebrewSearchKey) -> Vec<LexiconEntry>;
+
+        /// Returns the entries with the given root; separators between the root letters are ignored.
+        fn lookup_root(&self, root: &str) -> Vec<LexiconEntry>;
+    }
+
+    /// The error returned when a [`MemoryLexicon`] cannot be loaded.
+    #[derive(Debug)]
+    pub enum LexiconError {
+        /// The file could not be read
+        Io(io::Error),
+        /// A TSV line without a lemma, at the given line number (starting at 1)
+        MissingColumn(usize),
+        /// Malformed JSON at the given byte offset
+        InvalidJson(usize),
+        /// A JSON entry, at the given index, without the given field or with a field of the wrong type
+        InvalidField(usize, &'static str),
+    }
+
+    impl fmt::Display for LexiconError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                LexiconError::Io(error) => write!(f, "cannot read the lexicon: {}", error),
+                LexiconError::MissingColumn(line) => {
+                    write!(f, "line {} has no lemma column", line)
+                }
+                LexiconError::InvalidJson(at) => write!(f, "invalid JSON at byte {}", at),
+                LexiconError::InvalidField(index, field) => {
+                    write!(f, "entry {} has no valid \"{}\"", index, field)
+                }
+            }
+        }
+    }
+
+    impl Error for LexiconError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            match self {
+                LexiconError::Io(error) => Some(error),
+                _ => None,
+            }
+        }
+    }
+
+    impl From<io::Error> for LexiconError {
+        fn from(error: io::Error) -> Self {
+            LexiconError::Io(error)
+        }
+    }
+
+    /// A lexicon kept in memory, indexed by form, [`HebrewSearchKey`] and root.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{HebrewLexicon, HebrewSearchKey, MemoryLexicon};
+    ///
+    /// let tsv = "# form\tlemma\troot\ttags\nכָּתַבְתִּי\tכָּתַב\tכתב\tverb,qal\nמִכְתָּב\tמִכְתָּב\tכתב\tnoun\n";
+    /// let lexicon = MemoryLexicon::from_tsv(tsv).unwrap();
+    /// assert_eq!(lexicon.len(), 2);
+    /// assert_eq!(lexicon.lookup_form("מִכְתָּב")[0].tags, vec!["noun"]);
+    /// assert_eq!(lexicon.lookup_key(&HebrewSearchKey::new("כתבתי"))[0].lemma, "כָּתַב");
+    /// assert_eq!(lexicon.lookup_root("כ־ת־ב").len(), 2);
+    /// ```
+    #[derive(Debug, Clone, Default)]
+    pub struct MemoryLexicon {
+        entries: Vec<LexiconEntry>,
+        by_form: HashMap<String, Vec<usize>>,
+        by_key: HashMap<HebrewSearchKey, Vec<usize>>,
+        by_root: HashMap<String, Vec<usize>>,
+    }
+
+    impl MemoryLexicon {
+        /// Creates an empty lexicon.
+        pub fn new() -> MemoryLexicon {
+            Default::default()
+        }
+
+        /// Adds an entry to the lexicon.
+        pub fn insert(&mut self, entry: LexiconEntry) {
+            let index = self.entries.len();
+            self.by_form
+                .entry(entry.form.clone())
+                .or_default()
+                .push(index);
+            self.by_key
+                .entry(HebrewSearchKey::new(&entry.form))
+                .or_default()
+                .push(index);
+            if let Some(root) = &entry.root {
+                self.by_root.entry(root_key(root)).or_default().push(index);
+            }
+            self.entries.push(entry);
+        }
+
+        /// Returns all entries in the order they were added.
+        pub fn entries(&self) -> &[LexiconEntry] {
+            &self.entries
+        }
+
+        /// Returns the number of entries.
+        pub fn len(&self) -> usize {
+            self.entries.len()
+        }
+
+        /// Checks if the lexicon has no entries.
+        pub fn is_empty(&self) -> bool {
+            self.entries.is_empty()
+        }
+
+        /// Reads a lexicon from a file in the TSV or JSON format, see [`MemoryLexicon::from_tsv`]
+        /// and [`MemoryLexicon::from_json`]; a file starting with `[` is read as JSON.
+        pub fn load<P: AsRef<Path>>(path: P) -> Result<MemoryLexicon, LexiconError> {
+            let text = std::fs::read_to_string(path)?;
+            if text.trim_start().starts_with('[') {
+                MemoryLexicon::from_json(&text)
+            } else {
+                MemoryLexicon::from_tsv(&text)
+            }
+        }
+
+        /// Parses a lexicon with one entry per line: form, lemma, root and comma separated tags,
+        /// separated by tabs. The root and tags are optional, empty lines and lines starting with `#` are skipped.
+        pub fn from_tsv(text: &str) -> Result<MemoryLexicon, LexiconError> {
+            let mut lexicon = MemoryLexicon::new();
+            for (number, line) in text.lines().enumerate() {
+                if line.trim().is_empty() || line.starts_with('#') {
+                    continue;
+                }
+                let mut columns = line.split('\t').map(str::trim);
+                let form = columns.next().unwrap_or_default();
+                let lemma = match columns.next() {
+                    Some(lemma) if !lemma.is_empty() && !form.is_empty() => lemma,
+                    _ => return Err(LexiconError::MissingColumn(number + 1)),
+                };
+                let root = columns.next().filter(|root| !root.is_empty());
+                let tags = columns.next().map_or(Vec::new(), |tags| {
+                    tags.split(',')
+                        .map(str::trim)
+                        .filter(|tag| !tag.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                });
+                lexicon.insert(LexiconEntry {
+                    form: form.to_string(),
+                    lemma: lemma.to_string(),
+                    root: root.map(str::to_string),
+                    tags,
+                });
+            }
+            Ok(lexicon)
+        }
+
+        /// Parses a lexicon from a JSON array of objects with the string fields `form` and `lemma`,
+        /// an optional string (or `null`) `root` and an optional array of strings `tags`; other fields are ignored.
+        /// Arrays and objects nested more than 128 deep are rejected.
+        ///
+        /// # Examples
+        /// ```
+        /// use hebrew_unicode_utils::{HebrewLexicon, MemoryLexicon};
+        ///
+        /// let json = r#"[{"form": "שָׁלוֹם", "lemma": "שָׁלוֹם", "root": "שלם", "tags": ["noun"]},
+        ///                {"form": "שלום", "lemma": "שָׁלוֹם", "root": null}]"#;
+        /// let lexicon = MemoryLexicon::from_json(json).unwrap();
+        /// assert_eq!(lexicon.lookup_form("שלום")[0].lemma, "שָׁלוֹם");
+        /// assert_eq!(lexicon.lookup_root("שלם").len(), 1);
+        /// ```
+        pub fn from_json(text: &str) -> Result<MemoryLexicon, LexiconError> {
+            let items = match parse_json(text).map_err(LexiconError::InvalidJson)? {
+                Json::Array(items) => items,
+                _ => return Err(LexiconError::InvalidJson(0)),
+            };
+            let mut lexicon = MemoryLexicon::new();
+            for (index, item) in items.into_iter().enumerate() {
+                let mut fields = match item {
+                    Json::Object(fields) => fields,
+                    _ => return Err(LexiconError::InvalidField(index, "form")),
+                };
+                let mut take = |name: &str| {
+                    fields
+                        .iter()
+                        .position(|(key, _)| key == name)
+                        .map(|i| fields.swap_remove(i).1)
+                };
+                let form = match take("form") {
+                    Some(Json::String(form)) => form,
+                    _ => return Err(LexiconError::InvalidField(index, "form")),
+                };
+                let lemma = match take("lemma") {
+                    Some(Json::String(lemma)) => lemma,
+                    _ => return Err(LexiconError::InvalidField(index, "lemma")),
+                };
+                let root = match take("root") {
+                    Some(Json::String(root)) => Some(root),
+                    None | Some(Json::Null) => None,
+                    _ => return Err(LexiconError::InvalidField(index, "root")),
+                };
+                let tags = match take("tags") {
+                    Some(Json::Array(tags)) => tags
+                        .into_iter()
+                        .map(|tag| match tag {
+                            Json::String(tag) => Ok(tag),
+                            _ => Err(LexiconError::InvalidField(index, "tags")),
+                        })
+                        .collect::<Result<Vec<String>, LexiconError>>()?,
+                    None | Some(Json::Null) => Vec::new(),
+                    _ => return Err(LexiconError::InvalidField(index, "tags")),
+                };
+                lexicon.insert(LexiconEntry {
+                    form,
+                    lemma,
+                    root,
+                    tags,
+                });
+            }
+            Ok(lexicon)
+        }
+
+        fn collect(&self, indices: Option<&Vec<usize>>) -> Vec<LexiconEntry> {
+            indices.map_or(Vec::new(), |indices| {
+                indices.iter().map(|&i| self.entries[i].clone()).collect()
+            })
+        }
+    }
+
+    impl HebrewLexicon for MemoryLexicon {
+        fn lookup_form(&self, form: &str) -> Vec<LexiconEntry> {
+            self.collect(self.by_form.get(form))
+        }
+
+        fn lookup_key(&self, key: &HebrewSearchKey) -> Vec<LexiconEntry> {
+            self.collect(self.by_key.get(key))
+        }
+
+        fn lookup_root(&self, root: &str) -> Vec<LexiconEntry> {
+            self.collect(self.by_root.get(&root_key(root)))
+        }
+    }
+
+    /// A lemma found by [`lemmatize_hbr`], with the way the word was split to find it.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct LemmaCandidate {
+        /// The lexicon entry of the stem
+        pub entry: LexiconEntry,
+        /// The prefix letters split off the word, see [`crate::HebrewSegmentation`]
+        pub prefixes: Vec<char>,
+        /// The pronominal suffix split off the word, if any
+        pub suffix: Option<String>,
+        /// How plausible the lemma is, higher is more plausible
+        pub score: f64,
+    }
+
+    /// Looks up the lemmas of a Hebrew word in a lexicon, the most plausible first.
+    ///
+    /// Every segmentation of [`crate::segment_hbr_word`] is tried, from the whole word to the stem without
+    /// prefixes and suffix. A stem is looked up by its exact form, then by its form without points
+    /// ending in a final letter, then by its [`HebrewSearchKey`], so that a pointed word finds unpointed entries
+    /// and the other way around, and a stem like מלכ of מלכו finds the entry מלך.
+    /// An exact match scores higher than a match by key, and a likely segmentation higher than an unlikely one.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{lemmatize_hbr, MemoryLexicon};
+    ///
+    /// let lexicon = MemoryLexicon::from_tsv("בַּיִת\tבַּיִת\tבית\nמֶלֶךְ\tמֶלֶךְ\tמלך\n").unwrap();
+    ///
+    /// let lemmas = lemmatize_hbr("וּבַבַּיִת", &lexicon);
+    /// assert_eq!(lemmas[0].entry.lemma, "בַּיִת");
+    /// assert_eq!(lemmas[0].prefixes, vec!['ו', 'ב', 'ה']);
+    ///
+    /// let lemmas = lemmatize_hbr("ובמלכו", &lexicon);
+    /// assert_eq!(lemmas[0].entry.lemma, "מֶלֶךְ");
+    /// assert_eq!(lemmas[0].suffix.as_deref(), Some("ו"));
+    /// assert!(lemmatize_hbr("שלום", &lexicon).is_empty());
+    /// ```
+    pub fn lemmatize_hbr<L: HebrewLexicon + ?Sized>(
+        word: &str,
+        lexicon: &L,
+    ) -> Vec<LemmaCandidate> {
+        let segmentations = segment_hbr_word(word);
+        let best = segmentations
+            .first()
+            .map_or(0.0, |s| s.score)
+            .max(f64::MIN_POSITIVE);
+        let mut candidates: Vec<LemmaCandidate> = Vec::new();
+        for segmentation in &segmentations {
+            let weight = segmentation.score / best;
+            let stem = &word[segmentation.stem_span.clone()];
+            let found = [
+                (lexicon.lookup_form(stem), 1.0),
+                (lexicon.lookup_form(&segmentation.stem), 0.9),
+                (lexicon.lookup_key(&HebrewSearchKey::new(stem)), 0.8),
+            ];
+            for (entries, match_weight) in found.iter() {
+                for entry in entries {
+                    let score = weight * match_weight;
+                    match candidates.iter_mut().find(|c| {
+                        c.entry == *entry
+                            && c.prefixes == segmentation.prefixes
+                            && c.suffix == segmentation.suffix
+                    }) {
+                        Some(candidate) => candidate.score = candidate.score.max(score),
+                        None => candidates.push(LemmaCandidate {
+                            entry: entry.clone(),
+                            prefixes: segmentation.prefixes.clone(),
+                            suffix: segmentation.suffix.clone(),
+                            score,
+                        }),
+                    }
+                }
+            }
+        }
+        candidates.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(a.prefixes.len().cmp(&b.prefixes.len()))
+        });
+        candidates
+    }
+}
+
+use crate::final_forms::to_normal_form;
+use crate::get_hbr_clusters;
+use hebrew_unicode_script::HebrewUnicodeScript;
+
+/// The letters of a root in normal form, without points and separators.
+pub(crate) fn root_key(root: &str) -> String {
+    get_hbr_clusters(root)
+        .iter()
+        .filter_map(|cluster| cluster.base().filter(|c| c.is_hbr_consonant()))
+        .map(to_normal_form)
+        .collect()
+}
+
+/// A parsed JSON value; numbers are kept as text, since a lexicon has no use for them.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Json {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+/// Parses a JSON document, returning the byte offset of the first error.
+pub(crate) fn parse_json(text: &str) -> Result<Json, usize> {
+    let mut parser = JsonParser {
+        text,
+        at: 0,
+        depth: 0,
+    };
+    let value = parser.value()?;
+    parser.whitespace();
+    if parser.at < text.len() {
+        return Err(parser.at);
+    }
+    Ok(value)
+}
+
+/// The deepest nesting of arrays and objects [`parse_json`] accepts.
+const MAX_JSON_DEPTH: usize = 128;
+
+struct JsonParser<'a> {
+    text: &'a str,
+    at: usize,
+    /// The number of arrays and objects the parser is in
+    depth: usize,
+}
+
+impl JsonParser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.text[self.at..].chars().next()
+    }
+
+    fn whitespace(&mut self) {
+        while let Some(c) = self
+            .peek()
+            .filter(|c| matches!(c, ' ' | '\t' | '\n' | '\r'))
+        {
+            self.at += c.len_utf8();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), usize> {
+        self.whitespace();
+        if self.peek() == Some(expected) {
+            self.at += expected.len_utf8();
+            Ok(())
+        } else {
+            Err(self.at)
+        }
+    }
+
+    fn value(&mut self) -> Result<Json, usize> {
+        self.whitespace();
+        match self.peek() {
+            Some('{') | Some('[') if self.depth == MAX_JSON_DEPTH => Err(self.at),
+            Some('{') => {
+                self.depth += 1;
+                let object = self.object();
+                self.depth -= 1;
+                object
+            }
+            Some('[') => {
+                self.depth += 1;
+                let array = self.array();
+                self.depth -= 1;
+                array
+            }
+            Some('"') => self.string().map(Json::String),
+            Some('-') | Some('0'..='9') => self.number(),
+            _ => {
+                for (word, value) in [
+                    ("null", Json::Null),
+                    ("true", Json::Bool(true)),
+                    ("false", Json::Bool(false)),
+                ] {
+                    if self.text[self.at..].starts_with(word) {
+                        self.at += word.len();
+                        return Ok(value);
+                    }
+                }
+                Err(self.at)
+            }
+        }
+    }
+
+    /// Parses a number: an optional minus, an integer without leading zeros,
+    /// an optional fraction and an optional exponent.
+    fn number(&mut self) -> Result<Json, usize> {
+        let start = self.at;
+        if self.peek() == Some('-') {
+            self.at += 1;
+        }
+        if self.peek() == Some('0') {
+            self.at += 1;
+        } else {
+            self.digits()?;
+        }
+        if self.peek() == Some('.') {
+            self.at += 1;
+            self.digits()?;
+        }
+        if matches!(self.peek(), Some('e') | Some('E')) {
+            self.at += 1;
+            if matches!(self.peek(), Some('+') | Some('-')) {
+                self.at += 1;
+            }
+            self.digits()?;
+        }
+        Ok(Json::Number(self.text[start..self.at].to_string()))
+    }
+
+    /// Skips one or more ASCII digits.
+    fn digits(&mut self) -> Result<(), usize> {
+        let start = self.at;
+        while self.peek().map_or(false, |c| c.is_ascii_digit()) {
+            self.at += 1;
+        }
+        if self.at == start {
+            return Err(self.at);
+        }
+        Ok(())
+    }
+
+    fn object(&mut self) -> Result<Json, usize> {
+        self.expect('{')?;
+        let mut fields = Vec::new();
+        self.whitespace();
+        if self.peek() == Some('}') {
+            self.at += 1;
+            return Ok(Json::Object(fields));
+        }
+        loop {
+            self.whitespace();
+            let key = self.string()?;
+            self.expect(':')?;
+            fields.push((key, self.value()?));
+            self.whitespace();
+            match self.peek() {
+                Some(',') => self.at += 1,
+                Some('}') => {
+                    self.at += 1;
+                    return Ok(Json::Object(fields));
+                }
+                _ => return Err(self.at),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Json, usize> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+        self.whitespace();
+        if self.peek() == Some(']') {
+            self.at += 1;
+            return Ok(Json::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            self.whitespace();
+            match self.peek() {
+                Some(',') => self.at += 1,
+                Some(']') => {
+                    self.at += 1;
+                    return Ok(Json::Array(items));
+                }
+                _ => return Err(self.at),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, usize> {
+        if self.peek() != Some('"') {
+            return Err(self.at);
+        }
+        self.at += 1;
+        let mut string = String::new();
+        loop {
+            let c = self.peek().ok_or(self.at)?;
+            let start = self.at;
+            self.at += c.len_utf8();
+            match c {
+                '"' => return Ok(string),
+                '\\' => {
+                    let escaped = self.peek().ok_or(self.at)?;
+                    self.at += escaped.len_utf8();
+                    match escaped {
+                        '"' | '\\' | '/' => string.push(escaped),
+                        'b' => string.push('\u{0008}'),
+                        'f' => string.push('\u{000C}'),
+                        'n' => string.push('\n'),
+                        'r' => string.push('\r'),
+                        't' => string.push('\t'),
+                        'u' => {
+                            let high = self.hex4()?;
+                            let code = if (0xD800..0xDC00).contains(&high) {
+                                if !self.text[self.at..].starts_with("\\u") {
+                                    return Err(self.at);
+                                }
+                                self.at += 2;
+                                let low = self.hex4()?;
+                                if !(0xDC00..0xE000).contains(&low) {
+                                    return Err(self.at);
+                                }
+                                0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+                            } else {
+                                high
+                            };
+                            string.push(char::from_u32(code).ok_or(start)?);
+                        }
+                        _ => return Err(start),
+                    }
+                }
+                c if c < ' ' => return Err(start),
+                c => string.push(c),
+            }
+        }
+    }
+
+    fn hex4(&mut self) -> Result<u32, usize> {
+        let digits = self
+            .text
+            .get(self.at..self.at + 4)
+            .filter(|digits| digits.bytes().all(|b| b.is_ascii_hexdigit()))
+            .ok_or(self.at)?;
+        let code = u32::from_str_radix(digits, 16).map_err(|_| self.at)?;
+        self.at += 4;
+        Ok(code)
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use crate::lexicon::{parse_json, Json};
+    use crate::*;
+
+    #[test]
+    fn lexicon_from_tsv() {
+        let tsv = "# comment\n\nסֵפֶר\tסֵפֶר\tספר\tnoun, masculine\r\nספרים\tסֵפֶר\n";
+        let lexicon = MemoryLexicon::from_tsv(tsv).unwrap();
+        assert_eq!(lexicon.len(), 2);
+        assert_eq!(lexicon.entries()[0].tags, vec!["noun", "masculine"]);
+        assert_eq!(lexicon.entries()[1].root, None);
+        assert_eq!(lexicon.lookup_key(&HebrewSearchKey::new("סְפָרִים")).len(), 1);
+        assert_eq!(lexicon.lookup_root("ס.פ.ר").len(), 1);
+        assert!(lexicon.lookup_form("ספר").is_empty());
+        assert!(matches!(
+            MemoryLexicon::from_tsv("ספר\tספר\nספרים\n"),
+            Err(LexiconError::MissingColumn(2))
+        ));
+    }
+
+    #[test]
+    fn lexicon_from_json() {
+        let json = r#"[{"form": "a\"b\\é😀", "lemma": "x", "freq": [1.5e3, true, {}]}]"#;
+        let lexicon = MemoryLexicon::from_json(json).unwrap();
+        assert_eq!(lexicon.entries()[0].form, "a\"b\\é😀");
+        assert_eq!(
+            parse_json(" [null, -1, \"\"] "),
+            Ok(Json::Array(vec![
+                Json::Null,
+                Json::Number("-1".to_string()),
+                Json::String(String::new())
+            ]))
+        );
+        assert!(matches!(
+            MemoryLexicon::from_json("[{\"form\": \"a\"}]"),
+            Err(LexiconError::InvalidField(0, "lemma"))
+        ));
+        assert!(matches!(
+            MemoryLexicon::from_json("[{\"form\": \"a\", \"lemma\": 1}]"),
+            Err(LexiconError::InvalidField(0, "lemma"))
+        ));
+        assert!(matches!(
+            MemoryLexicon::from_json("[{\"form\": \"a\",}]"),
+            Err(LexiconError::InvalidJson(14))
+        ));
+        assert!(matches!(
+            MemoryLexicon::from_json("{}"),
+            Err(LexiconError::InvalidJson(0))
+        ));
+    }
+
+    #[test]
+    fn lexicon_json_grammar() {
+        for number in ["0", "-0", "12", "-1.5", "1e3", "1.5E-3", "2e+10"] {
+            assert_eq!(parse_json(number), Ok(Json::Number(number.to_string())));
+        }
+        assert_eq!(parse_json("-"), Err(1));
+        assert_eq!(parse_json("1-2"), Err(1));
+        assert_eq!(parse_json("01"), Err(1));
+        assert_eq!(parse_json("1."), Err(2));
+        assert_eq!(parse_json("1e"), Err(2));
+        assert_eq!(parse_json("-.5"), Err(1));
+        assert_eq!(parse_json("\"\\u05D0\""), Ok(Json::String("א".to_string())));
+        assert_eq!(parse_json("\"\\u+12a\""), Err(3));
+        assert_eq!(parse_json("\"\\u12\""), Err(3));
+    }
+
+    #[test]
+    fn lexicon_json_nesting_limit() {
+        let nested = |depth| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(parse_json(&nested(128)).is_ok());
+        assert_eq!(parse_json(&nested(129)), Err(128));
+        assert!(matches!(
+            MemoryLexicon::from_json(&"[".repeat(200000)),
+            Err(LexiconError::InvalidJson(128))
+        ));
+    }
+
+    #[test]
+    fn lexicon_load_from_disk() {
+        let dir = std::env::temp_dir();
+        let tsv = dir.join(format!("hebrew_lexicon_{}.tsv", std::process::id()));
+        let json = dir.join(format!("hebrew_lexicon_{}.json", std::process::id()));
+        std::fs::write(&tsv, "מֶלֶךְ\tמֶלֶךְ\tמלך\n").unwrap();
+        std::fs::write(&json, "\n[{\"form\": \"מֶלֶךְ\", \"lemma\": \"מֶלֶךְ\"}]").unwrap();
+        let from_tsv = MemoryLexicon::load(&tsv);
+        let from_json = MemoryLexicon::load(&json);
+        std::fs::remove_file(&tsv).unwrap();
+        std::fs::remove_file(&json).unwrap();
+        assert_eq!(from_tsv.unwrap().lookup_root("מלך").len(), 1);
+        assert_eq!(from_json.unwrap().lookup_form("מֶלֶךְ").len(), 1);
+        assert!(matches!(
+            MemoryLexicon::load(dir.join("no such lexicon.tsv")),
+            Err(LexiconError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn lemmatize_pointed_and_unpointed() {
+        let mut lexicon = MemoryLexicon::new();
+        lexicon.insert(LexiconEntry {
+            form: "ספר".to_string(),
+            lemma: "סֵפֶר".to_string(),
+            root: Some("ספר".to_string()),
+            tags: vec!["noun".to_string()],
+        });
+        let lemmas = lemmatize_hbr("בַּסֵּפֶר", &lexicon);
+        assert_eq!(lemmas[0].entry.lemma, "סֵפֶר");
+        assert_eq!(lemmas[0].prefixes, vec!['ב', 'ה']);
+        let lemmas = lemmatize_hbr("וספר", &lexicon);
+        assert_eq!(lemmas[0].prefixes, vec!['ו']);
+        assert!(lemmas.windows(2).all(|w| w[0].score >= w[1].score));
+        assert!(lemmatize_hbr("", &lexicon).is_empty());
+        let dynamic: &dyn HebrewLexicon = &lexicon;
+        assert_eq!(
+            lemmatize_hbr("ספר", dynamic)[0].prefixes,
+            Vec::<char>::new()
+        );
+    }
+}
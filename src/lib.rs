@@ -1,10 +1,65 @@
 #![doc = include_str!("../README.md")]
 
+mod acronyms;
+mod bidi;
+mod clusters;
+mod collation;
+mod comparison;
+mod conjugation;
+mod encoding;
+mod final_forms;
+mod fuzzy;
+mod justification;
+mod lexicon;
+mod mojibake;
+mod offset_map;
+mod ordering;
+mod pattern;
+mod presentation_forms;
+mod punctuation;
 mod removing;
+mod repair;
+mod roots;
+mod script_runs;
+mod segmentation;
 mod showing;
 mod statistics;
+mod terminal;
+mod transcription;
+mod validation;
+mod vowels;
+mod width;
 
 // re-export
+pub use self::acronyms::unicode_block_hebrew::*;
+pub use self::bidi::unicode_block_hebrew::*;
+pub use self::clusters::unicode_block_hebrew::*;
+pub use self::collation::unicode_block_hebrew::*;
+pub use self::comparison::unicode_block_hebrew::*;
+pub use self::conjugation::unicode_block_hebrew::*;
+pub use self::encoding::unicode_block_hebrew::*;
+pub use self::final_forms::unicode_block_hebrew::*;
+pub use self::fuzzy::unicode_block_hebrew::*;
+pub use self::justification::unicode_block_alphabetic_presentation_form::*;
+pub use self::lexicon::unicode_block_hebrew::*;
+pub use self::mojibake::unicode_block_hebrew::*;
+pub use self::offset_map::unicode_block_hebrew::*;
+pub use self::ordering::unicode_block_hebrew::*;
+pub use self::pattern::unicode_block_hebrew::*;
+pub use self::presentation_forms::unicode_block_alphabetic_presentation_form::*;
+pub use self::punctuation::unicode_block_hebrew::*;
+pub use self::removing::unicode_block_alphabetic_presentation_form::*;
 pub use self::removing::unicode_block_hebrew::*;
+pub use self::repair::unicode_block_hebrew::*;
+pub use self::roots::unicode_block_hebrew::*;
+pub use self::script_runs::unicode_block_hebrew::*;
+pub use self::segmentation::unicode_block_hebrew::*;
+pub use self::showing::unicode_block_alphabetic_presentation_form::*;
 pub use self::showing::unicode_block_hebrew::*;
+pub use self::statistics::unicode_block_alphabetic_presentation_form::*;
 pub use self::statistics::unicode_block_hebrew::*;
+pub use self::terminal::unicode_block_hebrew::*;
+pub use self::transcription::unicode_block_hebrew::*;
+pub use self::validation::unicode_block_hebrew::*;
+pub use self::vowels::unicode_block_hebrew::*;
+pub use self::width::unicode_block_hebrew::*;
@@ -0,0 +1,255 @@
+pub mod unicode_block_hebrew {
+    use crate::encoding::table;
+    use crate::get_hbr_character_types;
+    use crate::mojibake::WINDOWS_1252;
+    use crate::HebrewEncoding;
+    use std::borrow::Cow;
+
+    /// The encoding that UTF-8 Hebrew was wrongly decoded with.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum MojibakeEncoding {
+        /// Western European (Windows)
+        Windows1252,
+        /// Western European (ISO-8859-1)
+        Latin1,
+        /// Hebrew (Windows)
+        Windows1255,
+    }
+
+    /// A recognized double encoding of Hebrew text.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct HebrewMojibake {
+        /// The encoding the UTF-8 bytes were wrongly decoded with
+        pub encoding: MojibakeEncoding,
+        /// The recovered text
+        pub repaired: String,
+        /// How much of the text was recovered, from 0.0 up to and including 1.0
+        pub confidence: f64,
+    }
+
+    /// The confidence from which [`repair_hbr_mojibake`] replaces the text.
+    const REPAIR_CONFIDENCE: f64 = 0.75;
+
+    /// The number of Hebrew letters that must be decoded from complete UTF-8 sequences.
+    const MIN_HEBREW_LETTERS: usize = 2;
+
+    /// The share of Hebrew letters among the recovered non-ASCII characters that are not Hebrew points,
+    /// accents, marks or punctuation.
+    const MIN_HEBREW_RATIO: f64 = 0.5;
+
+    /// Detects UTF-8 Hebrew that was decoded as Windows-1252, ISO-8859-1 or Windows-1255.
+    ///
+    /// Every candidate encoding is reversed and the bytes are decoded as UTF-8 again.
+    /// Every recovered character is classified by [`crate::get_hbr_character_types`]: a recovery only counts
+    /// when the repaired text has Hebrew consonants, at least two of them were decoded from complete sequences
+    /// and at least half of the other recovered non-ASCII characters are Hebrew consonants as well.
+    /// Bytes that the wrong decoding dropped cannot be recovered and become U+FFFD.
+    /// The confidence is the share of non-ASCII bytes that decoded to Hebrew characters.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{detect_hbr_mojibake, MojibakeEncoding};
+    ///
+    /// let detected = detect_hbr_mojibake("×ª×•×“×”").unwrap();
+    /// assert_eq!(detected.encoding, MojibakeEncoding::Windows1252);
+    /// assert_eq!(detected.repaired, "תודה");
+    /// assert_eq!(detected.confidence, 1.0);
+    /// assert_eq!(detect_hbr_mojibake("שלום"), None);
+    /// ```
+    pub fn detect_hbr_mojibake(text: &str) -> Option<HebrewMojibake> {
+        let mut best: Option<HebrewMojibake> = None;
+        for encoding in [
+            MojibakeEncoding::Latin1,
+            MojibakeEncoding::Windows1252,
+            MojibakeEncoding::Windows1255,
+        ] {
+            if let Some(candidate) = recover(text, encoding) {
+                if best
+                    .as_ref()
+                    .map_or(true, |b| candidate.confidence > b.confidence)
+                {
+                    best = Some(candidate);
+                }
+            }
+        }
+        best
+    }
+
+    /// Repairs UTF-8 Hebrew that was decoded as Windows-1252, ISO-8859-1 or Windows-1255.
+    ///
+    /// The text is only replaced when [`detect_hbr_mojibake`] recovers it with a confidence of at least 0.75.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::repair_hbr_mojibake;
+    ///
+    /// assert_eq!(repair_hbr_mojibake("×‘×•×§×¨ ×˜×•×‘").as_ref(), "בוקר טוב");
+    /// assert_eq!(repair_hbr_mojibake("café").as_ref(), "café");
+    /// assert_eq!(repair_hbr_mojibake("3×4").as_ref(), "3×4");
+    /// ```
+    pub fn repair_hbr_mojibake(text: &str) -> Cow<'_, str> {
+        match detect_hbr_mojibake(text) {
+            Some(detected) if detected.confidence >= REPAIR_CONFIDENCE => {
+                Cow::Owned(detected.repaired)
+            }
+            _ => Cow::Borrowed(text),
+        }
+    }
+
+    /// Returns the byte the given character was decoded from.
+    fn to_byte(c: char, encoding: MojibakeEncoding) -> Option<u8> {
+        let code = c as u32;
+        if code < 0x80 {
+            return Some(code as u8);
+        }
+        let upper_half = match encoding {
+            MojibakeEncoding::Latin1 => None,
+            MojibakeEncoding::Windows1252 => {
+                WINDOWS_1252.iter().position(|&u| u32::from(u) == code)
+            }
+            MojibakeEncoding::Windows1255 => table(HebrewEncoding::Windows1255)
+                .iter()
+                .position(|&u| u32::from(u) == code),
+        };
+        match upper_half {
+            Some(position) => Some(0x80 + position as u8),
+            // undefined bytes are often passed through as C1 controls
+            None if (0x80..=0x9F).contains(&code) => Some(code as u8),
+            None if encoding != MojibakeEncoding::Windows1255 && code <= 0xFF => Some(code as u8),
+            None => None,
+        }
+    }
+
+    fn recover(text: &str, encoding: MojibakeEncoding) -> Option<HebrewMojibake> {
+        let bytes = text
+            .chars()
+            .map(|c| to_byte(c, encoding))
+            .collect::<Option<Vec<u8>>>()?;
+        let non_ascii = bytes.iter().filter(|&&b| b >= 0x80).count();
+        if non_ascii == 0 {
+            return None;
+        }
+        let mut repaired = String::with_capacity(bytes.len());
+        let mut hebrew_bytes = 0;
+        let mut hebrew_letters = 0;
+        let mut other = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            let length = match bytes[i] {
+                0x00..=0x7F => 1,
+                0xC2..=0xDF => 2,
+                0xE0..=0xEF => 3,
+                0xF0..=0xF4 => 4,
+                _ => 0,
+            };
+            let decoded = bytes
+                .get(i..i + length.max(1))
+                .and_then(|sequence| std::str::from_utf8(sequence).ok());
+            match decoded {
+                Some(s) if length > 0 => {
+                    repaired.push_str(s);
+                    if length > 1 {
+                        let types = get_hbr_character_types(s);
+                        let hebrew = !types.non_hebrew && !types.whitespace;
+                        if types.consonant {
+                            hebrew_letters += 1;
+                        } else if !hebrew {
+                            other += 1;
+                        }
+                        if hebrew {
+                            hebrew_bytes += length;
+                        }
+                    }
+                    i += length;
+                }
+                _ => {
+                    repaired.push('\u{FFFD}');
+                    other += 1;
+                    i += 1;
+                }
+            }
+        }
+        if !get_hbr_character_types(&repaired).consonant
+            || hebrew_letters < MIN_HEBREW_LETTERS
+            || (hebrew_letters as f64) < MIN_HEBREW_RATIO * (hebrew_letters + other) as f64
+        {
+            return None;
+        }
+        Some(HebrewMojibake {
+            encoding,
+            repaired,
+            confidence: hebrew_bytes as f64 / non_ascii as f64,
+        })
+    }
+}
+
+/// The characters of the bytes 0x80..0x9F in Windows-1252, 0 marks an undefined byte.
+#[rustfmt::skip]
+pub(crate) const WINDOWS_1252: [u16; 32] = [
+    // 0x80
+    0x20AC, 0, 0x201A, 0x0192, 0x201E, 0x2026, 0x2020, 0x2021,
+    0x02C6, 0x2030, 0x0160, 0x2039, 0x0152, 0, 0x017D, 0,
+    // 0x90
+    0, 0x2018, 0x2019, 0x201C, 0x201D, 0x2022, 0x2013, 0x2014,
+    0x02DC, 0x2122, 0x0161, 0x203A, 0x0153, 0, 0x017E, 0x0178,
+];
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+
+    #[test]
+    fn mojibake_windows_1252() {
+        let garbled = "×ž×”? ×¢×•×“ 3 ×“×§×•×ª";
+        let detected = detect_hbr_mojibake(garbled).unwrap();
+        assert_eq!(detected.encoding, MojibakeEncoding::Windows1252);
+        assert_eq!(detected.repaired, "מה? עוד 3 דקות");
+        assert_eq!(detected.confidence, 1.0);
+    }
+
+    #[test]
+    fn mojibake_latin1_with_points() {
+        let original = "שָׁלוֹם";
+        let garbled: String = original.bytes().map(char::from).collect();
+        let detected = detect_hbr_mojibake(&garbled).unwrap();
+        assert_eq!(detected.encoding, MojibakeEncoding::Latin1);
+        assert_eq!(detected.repaired, original);
+    }
+
+    #[test]
+    fn mojibake_windows_1255() {
+        // ש (D7 A9) and ב (D7 91) read as Windows-1255
+        let garbled = "\u{05F3}\u{00A9}\u{05F3}\u{2018}";
+        let detected = detect_hbr_mojibake(garbled).unwrap();
+        assert_eq!(detected.encoding, MojibakeEncoding::Windows1255);
+        assert_eq!(detected.repaired, "שב");
+    }
+
+    #[test]
+    fn mojibake_lost_bytes() {
+        // the second byte of ם (D7 9D) is undefined in Windows-1252 and was dropped
+        let detected = detect_hbr_mojibake("×©×œ×•× ×¢×•×œ×").unwrap();
+        assert_eq!(detected.repaired, "שלו\u{FFFD} עול\u{FFFD}");
+        assert!(detected.confidence < 1.0);
+        assert_eq!(repair_hbr_mojibake("×’×“").as_ref(), "גד");
+    }
+
+    #[test]
+    fn mojibake_clean_text_is_untouched() {
+        for text in [
+            "שלום עולם",
+            "naïve café",
+            "abc",
+            "",
+            "3×4",
+            "size 2×3 m",
+            "a × b",
+        ] {
+            assert_eq!(detect_hbr_mojibake(text), None);
+            assert!(matches!(
+                repair_hbr_mojibake(text),
+                std::borrow::Cow::Borrowed(_)
+            ));
+        }
+    }
+}
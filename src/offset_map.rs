@@ -0,0 +1,209 @@
+pub mod unicode_block_hebrew {
+    use crate::offset_map::filter_with_map;
+    use crate::HebrewCharTypeSet;
+    use std::ops::Range;
+
+    /// Translates positions in a filtered string back to the string it was made from.
+    ///
+    /// Every character of the filtered string maps to the span of the character it came from,
+    /// extended over the points, accents and marks removed directly after it.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_point_with_map;
+    ///
+    /// let original = "שָׁלוֹם";
+    /// let (stripped, map) = remove_hbr_point_with_map(original);
+    /// assert_eq!(stripped, "שלום");
+    /// assert_eq!(map.to_original(2), 6);
+    /// assert_eq!(map.to_original_span(0..6), 0..12);
+    /// assert_eq!(map.char_to_original(2), Some(8..12));
+    /// ```
+    #[derive(Debug, Clone, PartialEq, Eq, Default)]
+    pub struct OffsetMap {
+        /// (byte offset in the filtered string, original span) of every filtered character
+        pub(crate) entries: Vec<(usize, Range<usize>)>,
+        pub(crate) original_len: usize,
+        pub(crate) filtered_len: usize,
+    }
+
+    impl OffsetMap {
+        /// Returns the number of characters in the filtered string.
+        pub fn len(&self) -> usize {
+            self.entries.len()
+        }
+
+        /// Checks if the filtered string is empty.
+        pub fn is_empty(&self) -> bool {
+            self.entries.is_empty()
+        }
+
+        /// Returns the original byte offset of the given byte offset in the filtered string.
+        ///
+        /// The end of the filtered string maps to the end of the original string.
+        pub fn to_original(&self, offset: usize) -> usize {
+            if offset >= self.filtered_len {
+                return self.original_len;
+            }
+            self.entries[self.entry_at(offset)].1.start
+        }
+
+        /// Returns the original span of the given byte span in the filtered string.
+        ///
+        /// The span includes the points, accents and marks of its last character.
+        pub fn to_original_span(&self, span: Range<usize>) -> Range<usize> {
+            let start = self.to_original(span.start);
+            if span.end <= span.start || span.start >= self.filtered_len {
+                return start..start;
+            }
+            let last = self.entry_at(span.end.min(self.filtered_len) - 1);
+            start..self.entries[last].1.end
+        }
+
+        /// Returns the original span of the character with the given index in the filtered string.
+        pub fn char_to_original(&self, index: usize) -> Option<Range<usize>> {
+            self.entries.get(index).map(|(_, span)| span.clone())
+        }
+
+        /// Returns the index of the entry containing the given byte offset of the filtered string.
+        fn entry_at(&self, offset: usize) -> usize {
+            self.entries
+                .partition_point(|&(start, _)| start <= offset)
+                .saturating_sub(1)
+        }
+    }
+
+    /// Finds all occurrences of `needle` in `haystack`, ignoring the given Hebrew character types,
+    /// and returns their byte spans in `haystack`.
+    ///
+    /// Presentation forms are decomposed in both strings before searching.
+    /// The spans include the points, accents and marks of the last matching letter.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{find_hbr, HebrewCharTypeSet};
+    ///
+    /// let haystack = "בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים";
+    /// let spans = find_hbr(haystack, "ברא", HebrewCharTypeSet::NIQQUD_AND_CANTILLATION);
+    /// assert_eq!(spans.len(), 2);
+    /// assert_eq!(&haystack[spans[1].clone()], "בָּרָ֣א");
+    /// ```
+    pub fn find_hbr(haystack: &str, needle: &str, fold: HebrewCharTypeSet) -> Vec<Range<usize>> {
+        let (needle, _) = filter_with_map(needle, |c| fold.matches(c), true);
+        if needle.is_empty() {
+            return Vec::new();
+        }
+        let (folded, map) = filter_with_map(haystack, |c| fold.matches(c), true);
+        folded
+            .match_indices(needle.as_str())
+            .map(|(start, found)| map.to_original_span(start..start + found.len()))
+            .collect()
+    }
+}
+
+use crate::clusters::is_hbr_combining;
+use crate::presentation_forms::decompose_apf_char;
+use unicode_block_hebrew::OffsetMap;
+
+/// Removes the characters for which `remove` returns true and records where every remaining character came from.
+///
+/// When `decompose` is set, presentation forms are decomposed first;
+/// all their characters map to the span of the presentation form.
+pub(crate) fn filter_with_map(
+    text: &str,
+    remove: impl Fn(char) -> bool,
+    decompose: bool,
+) -> (String, OffsetMap) {
+    let mut filtered = String::with_capacity(text.len());
+    let mut entries: Vec<(usize, std::ops::Range<usize>)> = Vec::new();
+    for (pos, c) in text.char_indices() {
+        let end = pos + c.len_utf8();
+        let decomposed = if decompose {
+            decompose_apf_char(c)
+        } else {
+            None
+        };
+        let mut kept = false;
+        let mut push = |d: char| {
+            if remove(d) {
+                return;
+            }
+            entries.push((filtered.len(), pos..end));
+            filtered.push(d);
+            kept = true;
+        };
+        match decomposed {
+            Some(s) => s.chars().for_each(&mut push),
+            None => push(c),
+        }
+        // removed points, accents and marks belong to the preceding character
+        if !kept && is_hbr_combining(c) {
+            if let Some(last) = entries.last_mut() {
+                if last.1.end == pos {
+                    let start = last.1.start;
+                    for entry in entries.iter_mut().rev() {
+                        if entry.1.start != start {
+                            break;
+                        }
+                        entry.1.end = end;
+                    }
+                }
+            }
+        }
+    }
+    let map = OffsetMap {
+        entries,
+        original_len: text.len(),
+        filtered_len: filtered.len(),
+    };
+    (filtered, map)
+}
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+
+    #[test]
+    fn offset_map_positions() {
+        let original = "אָב־בַּיִת";
+        let (stripped, map) = remove_hbr_point_with_map(original);
+        assert_eq!(stripped, "אב־בית");
+        assert_eq!(map.len(), 6);
+        assert_eq!(map.to_original(0), 0);
+        assert_eq!(map.to_original(stripped.len()), original.len());
+        for (i, (pos, c)) in stripped.char_indices().enumerate() {
+            let span = map.char_to_original(i).unwrap();
+            assert_eq!(original[span.clone()].chars().next(), Some(c));
+            assert_eq!(map.to_original(pos), span.start);
+        }
+        assert_eq!(&original[map.to_original_span(6..12)], "בַּיִת");
+        assert_eq!(map.to_original_span(4..4), 6..6);
+    }
+
+    #[test]
+    fn offset_map_presentation_forms() {
+        let original = "a\u{FB2C}\u{FB4F}b";
+        let (folded, map) = crate::offset_map::filter_with_map(
+            original,
+            |c| HebrewCharTypeSet::POINT.matches(c),
+            true,
+        );
+        assert_eq!(folded, "aשאלb");
+        assert_eq!(map.char_to_original(2), Some(4..7));
+        assert_eq!(map.char_to_original(3), Some(4..7));
+        assert_eq!(map.to_original_span(1..3), 1..4);
+    }
+
+    #[test]
+    fn find_in_pointed_text() {
+        let haystack = "וַיֹּ֣אמֶר אֱלֹהִ֔ים יְהִ֣י א֑וֹר וַֽיְהִי־אֽוֹר׃";
+        let spans = find_hbr(haystack, "אור", HebrewCharTypeSet::NIQQUD_AND_CANTILLATION);
+        let found: Vec<&str> = spans.iter().map(|span| &haystack[span.clone()]).collect();
+        assert_eq!(found, vec!["א֑וֹר", "אֽוֹר"]);
+        assert!(find_hbr(haystack, "", HebrewCharTypeSet::POINT).is_empty());
+        assert_eq!(
+            find_hbr(haystack, "אוֹר", HebrewCharTypeSet::ACCENT).len(),
+            1
+        );
+    }
+}
@@ -0,0 +1,374 @@
+pub mod unicode_block_hebrew {
+    use crate::clusters::is_hbr_combining;
+    use crate::ordering::{bidi_class, reorder_line, wrap_lines, BidiClass};
+    use crate::{validate_hbr, Severity};
+
+    /// Converts text in visual order into logical order.
+    ///
+    /// Every line is read from left to right as displayed in a right-to-left paragraph:
+    /// Hebrew runs are reversed, embedded Latin and digit runs keep their order and brackets are mirrored.
+    /// When the points, accents and marks of a Hebrew run were placed before their base letter,
+    /// they are reattached after it.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::visual_to_logical;
+    ///
+    /// assert_eq!(visual_to_logical("abc 123 םולש"), "שלום abc 123");
+    /// // a naive character reversal puts the points before their letter
+    /// let reversed: String = "שָׁלוֹם".chars().rev().collect();
+    /// assert_eq!(visual_to_logical(&reversed), "שָׁלוֹם");
+    /// ```
+    pub fn visual_to_logical(text: &str) -> String {
+        text.split('\n')
+            .map(|line| reorder_line(&attach_marks(line)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Converts text in logical order into visual order, for displays without bidirectional support.
+    ///
+    /// Lines longer than `width` columns are wrapped at whitespace first, a width of 0 disables wrapping.
+    /// Points, accents and marks stay after their base letter.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::logical_to_visual;
+    ///
+    /// assert_eq!(logical_to_visual("שלום abc 123", 0), "abc 123 םולש");
+    /// assert_eq!(logical_to_visual("אבג דהו (זח)", 8), "והד גבא\n(חז)");
+    /// ```
+    pub fn logical_to_visual(text: &str, width: usize) -> String {
+        wrap_lines(text, width)
+            .iter()
+            .map(|line| reorder_line(line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Moves combining characters that precede their base letter behind it.
+    ///
+    /// A Hebrew run starting with a combining character has its marks in front of their letters.
+    /// Otherwise the marks are only moved when the run does not end with a combining character
+    /// and moving them gives fewer malformed clusters, as reported by [`crate::validate_hbr`].
+    fn attach_marks(line: &str) -> String {
+        let chars: Vec<char> = line.chars().collect();
+        let mut result = String::with_capacity(line.len());
+        let mut start = 0;
+        while start < chars.len() {
+            let in_run = |c: char| is_hbr_combining(c) || bidi_class(c) == BidiClass::R;
+            if !in_run(chars[start]) {
+                result.push(chars[start]);
+                start += 1;
+                continue;
+            }
+            let mut end = start;
+            while end < chars.len() && in_run(chars[end]) {
+                end += 1;
+            }
+            let run = &chars[start..end];
+            let kept: String = run.iter().collect();
+            let moved = marks_behind(run);
+            let marks_before = is_hbr_combining(run[0])
+                || (!is_hbr_combining(run[run.len() - 1])
+                    && malformed_clusters(&moved) < malformed_clusters(&kept));
+            result.push_str(if marks_before { &moved } else { &kept });
+            start = end;
+        }
+        result
+    }
+
+    /// Returns the run with every sequence of combining characters moved behind the letter following it.
+    fn marks_behind(run: &[char]) -> String {
+        let mut moved = String::with_capacity(run.len() * 2);
+        let mut marks = Vec::new();
+        for &c in run {
+            if is_hbr_combining(c) {
+                marks.push(c);
+            } else {
+                moved.push(c);
+                moved.extend(marks.drain(..).rev());
+            }
+        }
+        moved.extend(marks.into_iter().rev());
+        moved
+    }
+
+    /// Returns the number of errors [`crate::validate_hbr`] finds in the given run.
+    fn malformed_clusters(run: &str) -> usize {
+        validate_hbr(run)
+            .iter()
+            .filter(|d| d.severity == Severity::Error)
+            .count()
+    }
+}
+
+use crate::clusters::is_hbr_combining;
+use crate::hbr_display_width;
+use hebrew_unicode_script::HebrewUnicodeScript;
+
+/// Splits the given text into lines of at most `width` columns, breaking at whitespace.
+///
+/// Existing line breaks are kept, a word longer than `width` gets a line of its own and a width of 0 disables wrapping.
+/// The whitespace between words is kept as it is, each whitespace character taking one column;
+/// the whitespace at a break is dropped.
+pub(crate) fn wrap_lines(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    for line in text.split('\n') {
+        if width == 0 {
+            lines.push(line.to_string());
+            continue;
+        }
+        let mut current = String::new();
+        let mut current_width = 0;
+        let mut rest = line;
+        while !rest.is_empty() {
+            let space_end = rest
+                .find(|c: char| !c.is_whitespace())
+                .unwrap_or(rest.len());
+            let (space, after) = rest.split_at(space_end);
+            let word_end = after.find(char::is_whitespace).unwrap_or(after.len());
+            let (word, after) = after.split_at(word_end);
+            let space_width = space.chars().count();
+            let word_width = hbr_display_width(word);
+            if !word.is_empty()
+                && current_width > 0
+                && current_width + space_width + word_width > width
+            {
+                lines.push(std::mem::take(&mut current));
+                current_width = 0;
+            } else {
+                current.push_str(space);
+                current_width += space_width;
+            }
+            current.push_str(word);
+            current_width += word_width;
+            rest = after;
+        }
+        lines.push(current);
+    }
+    lines
+}
+
+/// The simplified bidirectional class of a character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BidiClass {
+    /// Strong left-to-right
+    L,
+    /// Strong right-to-left
+    R,
+    /// European number
+    EN,
+    /// Neutral or weak
+    N,
+}
+
+/// Returns the simplified bidirectional class of the given character.
+pub(crate) fn bidi_class(c: char) -> BidiClass {
+    match c {
+        '0'..='9' | '\u{06F0}'..='\u{06F9}' => BidiClass::EN,
+        '\u{200E}' => BidiClass::L,
+        '\u{200F}' => BidiClass::R,
+        '\u{0590}'..='\u{08FF}' | '\u{FB1D}'..='\u{FDFF}' | '\u{FE70}'..='\u{FEFF}' => BidiClass::R,
+        _ if c.is_hbr_block() || c.is_apf_block() => BidiClass::R,
+        _ if c.is_alphabetic() => BidiClass::L,
+        _ => BidiClass::N,
+    }
+}
+
+/// Returns the mirrored counterpart of a bracket.
+fn mirror(c: char) -> char {
+    match c {
+        '(' => ')',
+        ')' => '(',
+        '[' => ']',
+        ']' => '[',
+        '{' => '}',
+        '}' => '{',
+        '<' => '>',
+        '>' => '<',
+        '«' => '»',
+        '»' => '«',
+        _ => c,
+    }
+}
+
+/// Reorders one line of a right-to-left paragraph, visual to logical and logical to visual alike.
+///
+/// Clusters are kept intact, so combining characters stay after their base character.
+pub(crate) fn reorder_line(line: &str) -> String {
+    let mut units: Vec<(char, &str)> = Vec::new();
+    let mut start = 0;
+    for (pos, c) in line.char_indices() {
+        if pos > start && !is_hbr_combining(c) {
+            units.push((
+                line[start..].chars().next().unwrap_or_default(),
+                &line[start..pos],
+            ));
+            start = pos;
+        }
+    }
+    if start < line.len() {
+        units.push((
+            line[start..].chars().next().unwrap_or_default(),
+            &line[start..],
+        ));
+    }
+    let bases: Vec<char> = units.iter().map(|&(c, _)| c).collect();
+    let levels = resolve_levels(&bases, true);
+
+    let mut order: Vec<usize> = (0..units.len()).collect();
+    for level in [2u8, 1] {
+        let mut i = 0;
+        while i < order.len() {
+            if levels[order[i]] < level {
+                i += 1;
+                continue;
+            }
+            let mut end = i;
+            while end < order.len() && levels[order[end]] >= level {
+                end += 1;
+            }
+            order[i..end].reverse();
+            i = end;
+        }
+    }
+
+    let mut reordered = String::with_capacity(line.len());
+    for i in order {
+        let (base, text) = units[i];
+        if levels[i] % 2 == 1 && mirror(base) != base {
+            reordered.push(mirror(base));
+            reordered.push_str(&text[base.len_utf8()..]);
+        } else {
+            reordered.push_str(text);
+        }
+    }
+    reordered
+}
+
+/// Resolves the embedding levels of a line of base characters, a lightweight subset of UAX #9.
+///
+/// Left-to-right paragraphs have level 0, right-to-left paragraphs level 1;
+/// numbers following Latin text count as left-to-right, other numbers are raised above the paragraph level
+/// and neutrals take the direction of their surroundings.
+pub(crate) fn resolve_levels(bases: &[char], rtl_paragraph: bool) -> Vec<u8> {
+    let mut classes: Vec<BidiClass> = bases.iter().map(|&c| bidi_class(c)).collect();
+
+    // separators and terminators belong to the number they are attached to
+    for i in 0..classes.len() {
+        if classes[i] != BidiClass::N {
+            continue;
+        }
+        let previous = i.checked_sub(1).map(|p| classes[p]);
+        let next = classes.get(i + 1).copied();
+        let attached = match bases[i] {
+            '.' | ',' | ':' | '/' | '-' | '+' => {
+                previous == Some(BidiClass::EN) && next == Some(BidiClass::EN)
+            }
+            '%' | '$' | '#' | '°' | '€' | '₪' => {
+                previous == Some(BidiClass::EN) || next == Some(BidiClass::EN)
+            }
+            _ => false,
+        };
+        if attached {
+            classes[i] = BidiClass::EN;
+        }
+    }
+
+    // numbers following left-to-right text are left-to-right
+    let paragraph = if rtl_paragraph {
+        BidiClass::R
+    } else {
+        BidiClass::L
+    };
+    let mut last_strong = paragraph;
+    for class in classes.iter_mut() {
+        match *class {
+            BidiClass::L | BidiClass::R => last_strong = *class,
+            BidiClass::EN if last_strong == BidiClass::L => *class = BidiClass::L,
+            _ => {}
+        }
+    }
+
+    // neutrals take the direction of their surroundings, numbers count as right-to-left
+    let strong = |class: BidiClass| match class {
+        BidiClass::L => Some(BidiClass::L),
+        BidiClass::R | BidiClass::EN => Some(BidiClass::R),
+        BidiClass::N => None,
+    };
+    let level = |class: BidiClass| match class {
+        BidiClass::L if rtl_paragraph => 2,
+        BidiClass::L => 0,
+        BidiClass::R => 1,
+        _ => 2,
+    };
+    (0..classes.len())
+        .map(|i| match classes[i] {
+            BidiClass::N => {
+                let before = classes[..i].iter().rev().find_map(|&c| strong(c));
+                let after = classes[i + 1..].iter().find_map(|&c| strong(c));
+                match (before.unwrap_or(paragraph), after.unwrap_or(paragraph)) {
+                    (b, a) if b == a => level(b),
+                    _ => u8::from(rtl_paragraph),
+                }
+            }
+            class => level(class),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+
+    #[test]
+    fn visual_logical_roundtrip() {
+        let logical = "בְּרֵאשִׁית בָּרָא (Genesis 1:1) אֱלֹהִים";
+        let visual = logical_to_visual(logical, 0);
+        assert_eq!(visual_to_logical(&visual), logical);
+    }
+
+    #[test]
+    fn visual_keeps_latin_and_numbers() {
+        assert_eq!(
+            logical_to_visual("פרק 12.5 מתוך 20%", 0),
+            "20% ךותמ 12.5 קרפ"
+        );
+        assert_eq!(logical_to_visual("abc def", 0), "abc def");
+        assert_eq!(logical_to_visual("אב abc def גד", 0), "דג abc def בא");
+    }
+
+    #[test]
+    fn visual_logical_roundtrip_pointed() {
+        for logical in ["שלוֹם", "אבא (שלוֹם) 12", "שָׁלוֹם עוֹלָם", "וַיֹּאמֶר אֱלֹהִים יְהִי אוֹר"]
+        {
+            let visual = logical_to_visual(logical, 0);
+            assert_eq!(visual_to_logical(&visual), logical);
+        }
+        assert_eq!(logical_to_visual("שלוֹם", 0), "םוֹלש");
+    }
+
+    #[test]
+    fn visual_marks_before_base() {
+        let logical = "שָׁלוֹם עוֹלָם";
+        let reversed: String = logical.chars().rev().collect();
+        assert_eq!(visual_to_logical(&reversed), logical);
+    }
+
+    #[test]
+    fn visual_wraps_lines() {
+        assert_eq!(logical_to_visual("אבג דהו זח", 7), "והד גבא\nחז");
+        assert_eq!(visual_to_logical("והד גבא\nחז"), "אבג דהו\nזח");
+    }
+
+    #[test]
+    fn wrap_lines_keeps_whitespace() {
+        use crate::ordering::wrap_lines;
+        assert_eq!(wrap_lines("אב  גד\tהו", 0), ["אב  גד\tהו"]);
+        assert_eq!(wrap_lines("אב  גד\tהו", 20), ["אב  גד\tהו"]);
+        assert_eq!(wrap_lines("אב  גד\tהו", 6), ["אב  גד", "הו"]);
+        assert_eq!(wrap_lines("  אב גד", 5), ["  אב", "גד"]);
+        assert_eq!(wrap_lines("אבגדהו זח", 4), ["אבגדהו", "זח"]);
+    }
+}
@@ -0,0 +1,395 @@
+pub mod unicode_block_hebrew {
+    use crate::clusters::is_hbr_combining;
+    use crate::final_forms::to_normal_form;
+    use crate::{decompose_apf, get_hbr_clusters, HebrewCluster};
+    use hebrew_unicode_script::HebrewUnicodeScript;
+    use std::error::Error;
+    use std::fmt;
+    use std::ops::Range;
+
+    /// The error returned when a pattern cannot be compiled or a root does not fit it.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum PatternError {
+        /// A class other than `{C}`, `{V}`, `{A}`, `{M}` or `{P}` at the given byte offset
+        UnknownClass(usize),
+        /// A `{` without `}` at the given byte offset
+        UnclosedClass(usize),
+        /// A point, accent, mark or mark class without a preceding letter at the given byte offset
+        DanglingMark(usize),
+        /// A `?` without a preceding letter or mark at the given byte offset
+        DanglingOptional(usize),
+        /// The root has no letter for the given slot of the pattern
+        MissingRootLetter(u8),
+    }
+
+    impl fmt::Display for PatternError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                PatternError::UnknownClass(at) => write!(f, "unknown class at byte {}", at),
+                PatternError::UnclosedClass(at) => write!(f, "unclosed class at byte {}", at),
+                PatternError::DanglingMark(at) => {
+                    write!(f, "mark without a letter at byte {}", at)
+                }
+                PatternError::DanglingOptional(at) => {
+                    write!(f, "'?' without a letter or mark at byte {}", at)
+                }
+                PatternError::MissingRootLetter(slot) => {
+                    write!(f, "the root has no letter for slot {}", slot)
+                }
+            }
+        }
+    }
+
+    impl Error for PatternError {}
+
+    /// A match found by [`HebrewPattern::find_all`].
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct HebrewPatternMatch {
+        /// The byte offsets of the match within the searched string
+        pub span: Range<usize>,
+        /// The letters matched by the root slots 1, 2, 3 ..., final letters in their normal form
+        pub root: String,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum BaseMatcher {
+        /// A letter (in its normal form) or any other character
+        Literal(char),
+        /// `{C}`: any consonant
+        Consonant,
+        /// `1`..`9`: a root letter, bound by [`HebrewPattern::with_root`] or by the first match
+        Slot(u8, Option<char>),
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum MarkMatcher {
+        Literal(char),
+        /// `{V}`: a vowel or semi-vowel point
+        Vowel,
+        /// `{A}`: an accent
+        Accent,
+        /// `{M}`: a mark
+        Mark,
+        /// `{P}`: any point
+        Point,
+    }
+
+    impl MarkMatcher {
+        fn matches(self, c: char) -> bool {
+            match self {
+                MarkMatcher::Literal(m) => c == m,
+                MarkMatcher::Vowel => c.is_hbr_point_vowel() || c.is_hbr_point_semi_vowel(),
+                MarkMatcher::Accent => c.is_hbr_accent(),
+                MarkMatcher::Mark => c.is_hbr_mark(),
+                MarkMatcher::Point => c.is_hbr_point() || c.is_apf_point_reading_sign(),
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct Element {
+        base: BaseMatcher,
+        /// (required mark, optional)
+        marks: Vec<(MarkMatcher, bool)>,
+        optional: bool,
+    }
+
+    /// A compiled pattern for Hebrew text.
+    ///
+    /// A pattern is matched cluster by cluster, a letter with its points, accents and marks:
+    /// - a letter matches that letter; final and normal forms are equal
+    /// - `{C}` matches any consonant
+    /// - `1` .. `9` are the slots of a root template and match a consonant;
+    ///   the same digit matches the same letter every time
+    /// - a point, accent or mark after a letter must be on that letter,
+    ///   `{V}` requires a vowel point (including shva and hataf), `{A}` an accent, `{M}` a mark and `{P}` any point
+    /// - `?` makes the preceding letter or mark optional
+    /// - any other character matches itself
+    ///
+    /// Points, accents and marks that the pattern does not mention are allowed, unless the pattern is strict.
+    /// Presentation forms in the pattern are decomposed, searched text should be decomposed with [`crate::decompose_apf`].
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::HebrewPattern;
+    ///
+    /// let pattern = HebrewPattern::new("מִ12ָ3").unwrap().with_root("כ־ת־ב").unwrap();
+    /// let found = pattern.find_all("הַמִּכְתָּב נִשְׁלַח");
+    /// assert_eq!(found.len(), 1);
+    /// assert_eq!(found[0].span, 4..22);
+    /// assert_eq!(found[0].root, "כתב");
+    ///
+    /// let any_vowel = HebrewPattern::new("ש{V}?ל{V}?ו{P}?ם").unwrap();
+    /// assert!(any_vowel.matches("שָׁלוֹם"));
+    /// assert!(any_vowel.matches("שלום"));
+    /// ```
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct HebrewPattern {
+        elements: Vec<Element>,
+        strict: bool,
+    }
+
+    impl HebrewPattern {
+        /// Compiles the given pattern.
+        pub fn new(pattern: &str) -> Result<HebrewPattern, PatternError> {
+            let pattern = decompose_apf(pattern);
+            let mut elements: Vec<Element> = Vec::new();
+            // whether a `?` applies to the last mark instead of the last element
+            let mut last_was_mark = false;
+            let mut chars = pattern.char_indices();
+            while let Some((at, c)) = chars.next() {
+                let mark = match c {
+                    '{' => {
+                        let mut class = String::new();
+                        loop {
+                            match chars.next() {
+                                Some((_, '}')) => break,
+                                Some((_, n)) => class.push(n),
+                                None => return Err(PatternError::UnclosedClass(at)),
+                            }
+                        }
+                        match class.as_str() {
+                            "C" => None,
+                            "V" => Some(MarkMatcher::Vowel),
+                            "A" => Some(MarkMatcher::Accent),
+                            "M" => Some(MarkMatcher::Mark),
+                            "P" => Some(MarkMatcher::Point),
+                            _ => return Err(PatternError::UnknownClass(at)),
+                        }
+                    }
+                    '?' => {
+                        match elements.last_mut() {
+                            Some(element) if last_was_mark => {
+                                if let Some(last) = element.marks.last_mut() {
+                                    last.1 = true;
+                                }
+                            }
+                            Some(element) => element.optional = true,
+                            None => return Err(PatternError::DanglingOptional(at)),
+                        }
+                        continue;
+                    }
+                    _ if is_hbr_combining(c) => Some(MarkMatcher::Literal(c)),
+                    _ => None,
+                };
+                match mark {
+                    Some(mark) => match elements.last_mut() {
+                        Some(element) => element.marks.push((mark, false)),
+                        None => return Err(PatternError::DanglingMark(at)),
+                    },
+                    None => {
+                        let base = match c {
+                            '{' => BaseMatcher::Consonant,
+                            '1'..='9' => BaseMatcher::Slot(c as u8 - b'0', None),
+                            _ => BaseMatcher::Literal(to_normal_form(c)),
+                        };
+                        elements.push(Element {
+                            base,
+                            marks: Vec::new(),
+                            optional: false,
+                        });
+                    }
+                }
+                last_was_mark = mark.is_some();
+            }
+            Ok(HebrewPattern {
+                elements,
+                strict: false,
+            })
+        }
+
+        /// Returns a pattern that only allows the points, accents and marks it mentions.
+        ///
+        /// # Examples
+        /// ```
+        /// use hebrew_unicode_utils::HebrewPattern;
+        ///
+        /// let pattern = HebrewPattern::new("בּ?ַ?ת").unwrap().strict(true);
+        /// assert!(pattern.matches("בַּת"));
+        /// assert!(pattern.matches("בת"));
+        /// assert!(!pattern.matches("בִּת"));
+        /// ```
+        pub fn strict(mut self, strict: bool) -> HebrewPattern {
+            self.strict = strict;
+            self
+        }
+
+        /// Fills the slots of a root template with the letters of the given root.
+        ///
+        /// Only the consonants of the root are used, so `כתב`, `כ־ת־ב` and `כ.ת.ב` are the same root.
+        pub fn with_root(&self, root: &str) -> Result<HebrewPattern, PatternError> {
+            let letters: Vec<char> = root
+                .chars()
+                .filter(|c| c.is_hbr_consonant())
+                .map(to_normal_form)
+                .collect();
+            let mut pattern = self.clone();
+            for element in pattern.elements.iter_mut() {
+                if let BaseMatcher::Slot(slot, _) = element.base {
+                    match letters.get(usize::from(slot) - 1) {
+                        Some(&letter) => element.base = BaseMatcher::Slot(slot, Some(letter)),
+                        None => return Err(PatternError::MissingRootLetter(slot)),
+                    }
+                }
+            }
+            Ok(pattern)
+        }
+
+        /// Checks if the whole given string matches the pattern.
+        pub fn matches(&self, text: &str) -> bool {
+            let clusters = get_hbr_clusters(text);
+            self.match_from(&clusters, 0, 0, [None; 9], true).is_some()
+        }
+
+        /// Finds all non-overlapping matches of the pattern in the given string, from left to right.
+        pub fn find_all(&self, text: &str) -> Vec<HebrewPatternMatch> {
+            let clusters = get_hbr_clusters(text);
+            let mut found = Vec::new();
+            let mut start = 0;
+            while start < clusters.len() {
+                match self.match_from(&clusters, 0, start, [None; 9], false) {
+                    Some((end, slots)) if end > start => {
+                        found.push(HebrewPatternMatch {
+                            span: clusters[start].span.start..clusters[end - 1].span.end,
+                            root: slots.iter().flatten().collect(),
+                        });
+                        start = end;
+                    }
+                    _ => start += 1,
+                }
+            }
+            found
+        }
+
+        /// Matches the elements from `element` on against the clusters from `cluster` on,
+        /// preferring to match optional elements.
+        fn match_from(
+            &self,
+            clusters: &[HebrewCluster<'_>],
+            element: usize,
+            cluster: usize,
+            slots: [Option<char>; 9],
+            to_end: bool,
+        ) -> Option<(usize, [Option<char>; 9])> {
+            let current = match self.elements.get(element) {
+                Some(current) => current,
+                None if to_end && cluster < clusters.len() => return None,
+                None => return Some((cluster, slots)),
+            };
+            if let Some(text) = clusters.get(cluster) {
+                let mut bound = slots;
+                if self.element_matches(current, text, &mut bound) {
+                    let next = self.match_from(clusters, element + 1, cluster + 1, bound, to_end);
+                    if next.is_some() {
+                        return next;
+                    }
+                }
+            }
+            if current.optional {
+                return self.match_from(clusters, element + 1, cluster, slots, to_end);
+            }
+            None
+        }
+
+        fn element_matches(
+            &self,
+            element: &Element,
+            cluster: &HebrewCluster<'_>,
+            slots: &mut [Option<char>; 9],
+        ) -> bool {
+            let base = match cluster.base() {
+                Some(base) => base,
+                None => return false,
+            };
+            let base_matches = match element.base {
+                BaseMatcher::Literal(c) => to_normal_form(base) == c,
+                BaseMatcher::Consonant => base.is_hbr_consonant(),
+                BaseMatcher::Slot(slot, Some(c)) => {
+                    slots[usize::from(slot) - 1] = Some(c);
+                    to_normal_form(base) == c
+                }
+                BaseMatcher::Slot(slot, None) => {
+                    let bound = &mut slots[usize::from(slot) - 1];
+                    match *bound {
+                        _ if !base.is_hbr_consonant() => false,
+                        Some(c) => to_normal_form(base) == c,
+                        None => {
+                            *bound = Some(to_normal_form(base));
+                            true
+                        }
+                    }
+                }
+            };
+            if !base_matches {
+                return false;
+            }
+            let required = element
+                .marks
+                .iter()
+                .filter(|(_, optional)| !optional)
+                .all(|(mark, _)| cluster.marks().any(|c| mark.matches(c)));
+            let allowed = !self.strict
+                || cluster
+                    .marks()
+                    .all(|c| element.marks.iter().any(|(mark, _)| mark.matches(c)));
+            required && allowed
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+
+    #[test]
+    fn pattern_root_template() {
+        let template = HebrewPattern::new("מִ123ָה").unwrap();
+        let pattern = template.with_root("שמר").unwrap();
+        assert!(pattern.matches("מִשְׁמָרָה"));
+        assert!(!pattern.matches("מִשְׁמֶרֶת"));
+        assert!(!pattern.matches("משמרה"));
+        assert_eq!(
+            template.with_root("שמ"),
+            Err(PatternError::MissingRootLetter(3))
+        );
+    }
+
+    #[test]
+    fn pattern_unbound_slots_capture_the_root() {
+        let pattern = HebrewPattern::new("{C}?1ו2ֵ3").unwrap();
+        let found = pattern.find_all("הַכּוֹתֵב וְהַשּׁוֹמֵר");
+        let roots: Vec<&str> = found.iter().map(|m| m.root.as_str()).collect();
+        assert_eq!(roots, vec!["כתב", "שמר"]);
+        let repeated = HebrewPattern::new("1ו1").unwrap();
+        assert!(repeated.matches("תות"));
+        assert!(!repeated.matches("תוק"));
+    }
+
+    #[test]
+    fn pattern_classes_and_final_forms() {
+        let pattern = HebrewPattern::new("{C}{A}").unwrap();
+        assert_eq!(pattern.find_all("בְּרֵאשִׁ֖ית").len(), 1);
+        assert!(HebrewPattern::new("מלכ").unwrap().matches("מֶלֶךְ"));
+        assert!(HebrewPattern::new("\u{FB31}").unwrap().matches("בָּ"));
+    }
+
+    #[test]
+    fn pattern_errors() {
+        assert_eq!(
+            HebrewPattern::new("{X}").unwrap_err(),
+            PatternError::UnknownClass(0)
+        );
+        assert_eq!(
+            HebrewPattern::new("ב{V").unwrap_err(),
+            PatternError::UnclosedClass(2)
+        );
+        assert_eq!(
+            HebrewPattern::new("ָב").unwrap_err(),
+            PatternError::DanglingMark(0)
+        );
+        assert_eq!(
+            HebrewPattern::new("?").unwrap_err(),
+            PatternError::DanglingOptional(0)
+        );
+    }
+}
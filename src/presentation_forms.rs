@@ -0,0 +1,150 @@
+pub mod unicode_block_alphabetic_presentation_form {
+    use crate::presentation_forms::decompose_apf_char;
+    use std::borrow::Cow;
+
+    /// Decomposes all Hebrew presentation forms (U+FB1D..U+FB4F) into base letters and marks.
+    ///
+    /// Precomposed letters become a letter of the block 'Hebrew' followed by their points,
+    /// wide and alternative letters become their normal letter and the ligature alef lamed becomes two letters.
+    /// The point varika (U+FB1E) has no decomposition and is kept.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::decompose_apf;
+    ///
+    /// let test_str = "\u{FB2C}\u{FB31}\u{FB4F}";
+    /// let test_str_decomposed = decompose_apf(test_str);
+    /// assert_eq!(test_str_decomposed.as_ref(), "שּׁבּאל");
+    /// ```
+    pub fn decompose_apf(string: &str) -> Cow<'_, str> {
+        if !string.chars().any(|c| decompose_apf_char(c).is_some()) {
+            return Cow::Borrowed(string);
+        }
+        let mut decomposed = String::with_capacity(string.len() * 2);
+        for c in string.chars() {
+            match decompose_apf_char(c) {
+                Some(s) => decomposed.push_str(s),
+                None => decomposed.push(c),
+            }
+        }
+        Cow::Owned(decomposed)
+    }
+}
+
+use crate::decompose_apf;
+use std::borrow::Cow;
+
+/// Applies a string function after decomposing the Hebrew presentation forms,
+/// borrowing the given string when neither changes it.
+pub(crate) fn with_decomposed_apf<'a>(
+    string: &'a str,
+    f: for<'b> fn(&'b str) -> Cow<'b, str>,
+) -> Cow<'a, str> {
+    match decompose_apf(string) {
+        Cow::Borrowed(decomposed) => f(decomposed),
+        Cow::Owned(decomposed) => Cow::Owned(f(&decomposed).into_owned()),
+    }
+}
+
+/// Returns the decomposition of a Hebrew presentation form.
+pub(crate) fn decompose_apf_char(c: char) -> Option<&'static str> {
+    let decomposed = match c {
+        '\u{FB1D}' => "\u{05D9}\u{05B4}",
+        '\u{FB1F}' => "\u{05F2}\u{05B7}",
+        '\u{FB20}' => "\u{05E2}",
+        '\u{FB21}' => "\u{05D0}",
+        '\u{FB22}' => "\u{05D3}",
+        '\u{FB23}' => "\u{05D4}",
+        '\u{FB24}' => "\u{05DB}",
+        '\u{FB25}' => "\u{05DC}",
+        '\u{FB26}' => "\u{05DD}",
+        '\u{FB27}' => "\u{05E8}",
+        '\u{FB28}' => "\u{05EA}",
+        '\u{FB29}' => "+",
+        '\u{FB2A}' => "\u{05E9}\u{05C1}",
+        '\u{FB2B}' => "\u{05E9}\u{05C2}",
+        '\u{FB2C}' => "\u{05E9}\u{05BC}\u{05C1}",
+        '\u{FB2D}' => "\u{05E9}\u{05BC}\u{05C2}",
+        '\u{FB2E}' => "\u{05D0}\u{05B7}",
+        '\u{FB2F}' => "\u{05D0}\u{05B8}",
+        '\u{FB30}' => "\u{05D0}\u{05BC}",
+        '\u{FB31}' => "\u{05D1}\u{05BC}",
+        '\u{FB32}' => "\u{05D2}\u{05BC}",
+        '\u{FB33}' => "\u{05D3}\u{05BC}",
+        '\u{FB34}' => "\u{05D4}\u{05BC}",
+        '\u{FB35}' => "\u{05D5}\u{05BC}",
+        '\u{FB36}' => "\u{05D6}\u{05BC}",
+        '\u{FB38}' => "\u{05D8}\u{05BC}",
+        '\u{FB39}' => "\u{05D9}\u{05BC}",
+        '\u{FB3A}' => "\u{05DA}\u{05BC}",
+        '\u{FB3B}' => "\u{05DB}\u{05BC}",
+        '\u{FB3C}' => "\u{05DC}\u{05BC}",
+        '\u{FB3E}' => "\u{05DE}\u{05BC}",
+        '\u{FB40}' => "\u{05E0}\u{05BC}",
+        '\u{FB41}' => "\u{05E1}\u{05BC}",
+        '\u{FB43}' => "\u{05E3}\u{05BC}",
+        '\u{FB44}' => "\u{05E4}\u{05BC}",
+        '\u{FB46}' => "\u{05E6}\u{05BC}",
+        '\u{FB47}' => "\u{05E7}\u{05BC}",
+        '\u{FB48}' => "\u{05E8}\u{05BC}",
+        '\u{FB49}' => "\u{05E9}\u{05BC}",
+        '\u{FB4A}' => "\u{05EA}\u{05BC}",
+        '\u{FB4B}' => "\u{05D5}\u{05B9}",
+        '\u{FB4C}' => "\u{05D1}\u{05BF}",
+        '\u{FB4D}' => "\u{05DB}\u{05BF}",
+        '\u{FB4E}' => "\u{05E4}\u{05BF}",
+        '\u{FB4F}' => "\u{05D0}\u{05DC}",
+        _ => return None,
+    };
+    Some(decomposed)
+}
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+    use hebrew_unicode_script::HebrewUnicodeScript;
+
+    #[test]
+    fn decompose_apf_all_forms() {
+        for c in '\u{FB1D}'..='\u{FB4F}' {
+            let decomposed = decompose_apf(c.encode_utf8(&mut [0; 4])).into_owned();
+            if c == '\u{FB1E}' || !c.is_apf_block() {
+                assert_eq!(decomposed, c.to_string());
+            } else {
+                assert!(decomposed.chars().all(|d| !d.is_apf_block()));
+            }
+        }
+    }
+
+    #[test]
+    fn decompose_apf_keeps_other_text() {
+        let test_str = "abc בְּרֵאשִׁית";
+        assert!(matches!(
+            decompose_apf(test_str),
+            std::borrow::Cow::Borrowed(_)
+        ));
+        assert_eq!(decompose_apf("a\u{FB21}b").as_ref(), "aאb");
+    }
+
+    #[test]
+    fn with_decomposed_apf_borrows() {
+        use crate::presentation_forms::with_decomposed_apf;
+        use std::borrow::Cow;
+        assert!(matches!(
+            with_decomposed_apf("abc", decompose_apf),
+            Cow::Borrowed("abc")
+        ));
+        assert!(matches!(
+            with_decomposed_apf("\u{FB2F}", decompose_apf),
+            Cow::Owned(_)
+        ));
+        assert!(matches!(
+            with_decomposed_apf("שָׁלוֹם", remove_hbr_point),
+            Cow::Owned(_)
+        ));
+        assert_eq!(
+            with_decomposed_apf("\u{FB2F}", remove_hbr_point).as_ref(),
+            "א"
+        );
+    }
+}
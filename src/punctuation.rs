@@ -0,0 +1,210 @@
+pub mod unicode_block_hebrew {
+    use crate::punctuation::hebrew_punctuation;
+    use std::borrow::Cow;
+
+    /// The direction in which [`normalize_hbr_punctuation`] converts geresh, gershayim and maqaf.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum PunctuationDirection {
+        /// ASCII and typographic look-alikes in Hebrew words become geresh, gershayim and maqaf
+        ToHebrew,
+        /// Geresh, gershayim and maqaf become an ASCII apostrophe, quotation mark and hyphen, e.g. for URLs
+        ToAscii,
+    }
+
+    /// Which characters [`crate::remove_hbr_punctuation_with`] treats as punctuation.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct PunctuationOptions {
+        /// Geresh (U+05F3)
+        pub geresh: bool,
+        /// Gershayim (U+05F4)
+        pub gershayim: bool,
+        /// Maqaf (U+05BE)
+        pub maqaf: bool,
+        /// Paseq, sof pasuq and nun hafukha
+        pub other: bool,
+        /// The look-alikes of geresh, gershayim and maqaf in a Hebrew context, see [`normalize_hbr_punctuation`];
+        /// they are removed when their Hebrew counterpart is
+        pub look_alikes: bool,
+    }
+
+    impl Default for PunctuationOptions {
+        /// All Hebrew punctuation, no look-alikes; the same as [`crate::remove_hbr_punctuation`].
+        fn default() -> Self {
+            PunctuationOptions {
+                geresh: true,
+                gershayim: true,
+                maqaf: true,
+                other: true,
+                look_alikes: false,
+            }
+        }
+    }
+
+    impl PunctuationOptions {
+        /// Checks if the given Hebrew punctuation is selected.
+        pub(crate) fn selects(&self, c: char) -> bool {
+            match c {
+                '\u{05F3}' => self.geresh,
+                '\u{05F4}' => self.gershayim,
+                '\u{05BE}' => self.maqaf,
+                '\u{05C0}' | '\u{05C3}' | '\u{05C6}' => self.other,
+                _ => false,
+            }
+        }
+    }
+
+    /// Converts between Hebrew geresh (U+05F3), gershayim (U+05F4) and maqaf (U+05BE)
+    /// and the characters that are typed in their place.
+    ///
+    /// Towards Hebrew, only look-alikes in a Hebrew context are converted:
+    /// - an apostrophe (U+0027), right single quotation mark (U+2019) or modifier letter apostrophe (U+02BC)
+    ///   after a Hebrew letter becomes a geresh, as in ג'ירפה or the numeral ה',
+    ///   unless it closes a quotation opened with one of these characters
+    /// - a quotation mark (U+0022) or right double quotation mark (U+201D) between Hebrew letters becomes a gershayim,
+    ///   as in צה"ל or the numeral תשפ"ו
+    /// - a hyphen-minus (U+002D), hyphen (U+2010) or non-breaking hyphen (U+2011) between Hebrew letters becomes a maqaf
+    ///
+    /// Towards ASCII, every geresh, gershayim and maqaf is converted.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{normalize_hbr_punctuation, PunctuationDirection};
+    ///
+    /// let typed = "צה\"ל, ג’ירפה ותל-אביב 'שלום'";
+    /// let hebrew = normalize_hbr_punctuation(typed, PunctuationDirection::ToHebrew);
+    /// assert_eq!(hebrew, "צה״ל, ג׳ירפה ותל־אביב 'שלום'");
+    /// let ascii = normalize_hbr_punctuation(&hebrew, PunctuationDirection::ToAscii);
+    /// assert_eq!(ascii, "צה\"ל, ג'ירפה ותל-אביב 'שלום'");
+    /// ```
+    pub fn normalize_hbr_punctuation(text: &str, direction: PunctuationDirection) -> Cow<'_, str> {
+        match direction {
+            PunctuationDirection::ToHebrew => {
+                let replacements = hebrew_punctuation(text);
+                if replacements.is_empty() {
+                    return Cow::Borrowed(text);
+                }
+                let mut normalized = String::with_capacity(text.len());
+                let mut last = 0;
+                for (span, punctuation) in replacements {
+                    normalized.push_str(&text[last..span.start]);
+                    normalized.push(punctuation);
+                    last = span.end;
+                }
+                normalized.push_str(&text[last..]);
+                Cow::Owned(normalized)
+            }
+            PunctuationDirection::ToAscii => {
+                if !text.contains(&['\u{05F3}', '\u{05F4}', '\u{05BE}'][..]) {
+                    return Cow::Borrowed(text);
+                }
+                Cow::Owned(
+                    text.chars()
+                        .map(|c| match c {
+                            '\u{05F3}' => '\'',
+                            '\u{05F4}' => '"',
+                            '\u{05BE}' => '-',
+                            _ => c,
+                        })
+                        .collect(),
+                )
+            }
+        }
+    }
+}
+
+use crate::clusters::is_hbr_combining;
+use crate::presentation_forms::decompose_apf_char;
+use hebrew_unicode_script::HebrewUnicodeScript;
+use std::ops::Range;
+
+/// Checks if a character is a Hebrew letter, or a presentation form of one.
+pub(crate) fn is_letter(c: char) -> bool {
+    let letter = decompose_apf_char(c)
+        .and_then(|d| d.chars().next())
+        .unwrap_or(c);
+    letter.is_hbr_consonant() || letter.is_hbr_ligature_yiddish()
+}
+
+/// Characters typed in place of a geresh.
+fn is_geresh_like(c: char) -> bool {
+    matches!(c, '\'' | '\u{2019}' | '\u{02BC}')
+}
+
+/// Characters typed in place of a gershayim.
+fn is_gershayim_like(c: char) -> bool {
+    matches!(c, '"' | '\u{201D}')
+}
+
+/// Characters typed in place of a maqaf.
+pub(crate) fn is_maqaf_like(c: char) -> bool {
+    matches!(c, '-' | '\u{2010}' | '\u{2011}')
+}
+
+/// Characters after which an apostrophe opens a quotation.
+fn is_boundary(c: char) -> bool {
+    c.is_whitespace()
+        || c.is_ascii_punctuation()
+        || matches!(c, '«' | '»' | '\u{2010}'..='\u{2027}')
+}
+
+/// Finds the look-alikes of geresh, gershayim and maqaf in a Hebrew context,
+/// and returns their spans with the Hebrew punctuation that belongs there.
+pub(crate) fn hebrew_punctuation(text: &str) -> Vec<(Range<usize>, char)> {
+    let mut replacements = Vec::new();
+    let mut after_letter = false;
+    let mut after_boundary = true;
+    let mut quotation_open = false;
+    let mut chars = text.char_indices().peekable();
+    while let Some((pos, c)) = chars.next() {
+        if is_hbr_combining(c) {
+            continue;
+        }
+        let before_letter = chars.peek().map_or(false, |&(_, next)| is_letter(next));
+        let span = pos..pos + c.len_utf8();
+        if is_geresh_like(c) {
+            // an apostrophe within a word of another script, like "it's", is left alone
+            if after_boundary && before_letter {
+                quotation_open = true;
+            } else if after_letter && quotation_open {
+                quotation_open = false;
+            } else if after_letter {
+                replacements.push((span, '\u{05F3}'));
+            }
+        } else if is_gershayim_like(c) && after_letter && before_letter {
+            replacements.push((span, '\u{05F4}'));
+        } else if is_maqaf_like(c) && after_letter && before_letter {
+            replacements.push((span, '\u{05BE}'));
+        } else if c == '\n' {
+            quotation_open = false;
+        }
+        after_letter = is_letter(c);
+        after_boundary = is_boundary(c);
+    }
+    replacements
+}
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+
+    #[test]
+    fn punctuation_to_hebrew() {
+        let hebrew = |text| normalize_hbr_punctuation(text, PunctuationDirection::ToHebrew);
+        assert_eq!(hebrew("ה' בתשרי תשפ\"ו"), "ה׳ בתשרי תשפ״ו");
+        assert_eq!(hebrew("צ\u{02BC}יפס ו\u{2019}"), "צ׳יפס ו׳");
+        assert_eq!(hebrew("בֵּית־לֶ\u{2011}חֶם"), "בֵּית־לֶ־חֶם");
+        assert_eq!(hebrew("\"שלום\" it's a-b"), "\"שלום\" it's a-b");
+        assert_eq!(hebrew("'שלום'\n'ש'"), "'שלום'\n'ש'");
+        assert_eq!(hebrew("it's ג'ירפה"), "it's ג׳ירפה");
+        assert_eq!(hebrew("don't say ה' or צה\"ל"), "don't say ה׳ or צה״ל");
+        assert_eq!(hebrew("(ג'ירפה)"), "(ג׳ירפה)");
+        assert!(matches!(hebrew("שלום"), std::borrow::Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn punctuation_to_ascii() {
+        let ascii = |text| normalize_hbr_punctuation(text, PunctuationDirection::ToAscii);
+        assert_eq!(ascii("צה״ל/על־פי/ג׳"), "צה\"ל/על-פי/ג'");
+        assert!(matches!(ascii("a-b"), std::borrow::Cow::Borrowed(_)));
+    }
+}
@@ -2,6 +2,9 @@
 // use std::borrow::Cow;
 
 pub mod unicode_block_hebrew {
+    use crate::offset_map::filter_with_map;
+    use crate::punctuation::hebrew_punctuation;
+    use crate::{OffsetMap, PunctuationOptions};
     use hebrew_unicode_script::HebrewUnicodeScript;
     use std::borrow::Cow;
 
@@ -124,6 +127,38 @@ pub mod unicode_block_hebrew {
             .filter(|&c| !c.is_hbr_punctuation())
             .collect()
     }
+    /// Removes the Hebrew punctuation selected by the options from the given string.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{remove_hbr_punctuation_with, PunctuationOptions};
+    ///
+    /// let options = PunctuationOptions { maqaf: false, ..PunctuationOptions::default() };
+    /// let test_str_filtered = remove_hbr_punctuation_with("צה״ל על־ידי", &options);
+    /// assert_eq!(test_str_filtered.as_ref(), "צהל על־ידי");
+    /// let options = PunctuationOptions { look_alikes: true, ..PunctuationOptions::default() };
+    /// let test_str_filtered = remove_hbr_punctuation_with("צה\"ל על-ידי a-b", &options);
+    /// assert_eq!(test_str_filtered.as_ref(), "צהל עלידי a-b");
+    /// ```
+    pub fn remove_hbr_punctuation_with<'a>(
+        string: &'a str,
+        options: &PunctuationOptions,
+    ) -> Cow<'a, str> {
+        let look_alikes: Vec<usize> = if options.look_alikes {
+            hebrew_punctuation(string)
+                .into_iter()
+                .filter(|&(_, punctuation)| options.selects(punctuation))
+                .map(|(span, _)| span.start)
+                .collect()
+        } else {
+            Vec::new()
+        };
+        string
+            .char_indices()
+            .filter(|&(pos, c)| !options.selects(c) && look_alikes.binary_search(&pos).is_err())
+            .map(|(_, c)| c)
+            .collect()
+    }
     /// Removes all Hebrew letters (final and normal) from the given string.
     ///
     /// # Examples
@@ -201,6 +236,392 @@ pub mod unicode_block_hebrew {
             .filter(|&c| !c.is_hbr_ligature_yiddish())
             .collect()
     }
+
+    /// Removes all characters belonging to the unicode block 'Hebrew' from the given string and returns an [`crate::OffsetMap`]
+    /// from the result back to the given string.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_block_with_map;
+    ///
+    /// let (test_str_filtered, map) = remove_hbr_block_with_map("abcשָׁלוֹםdef");
+    /// assert_eq!(test_str_filtered, "abcdef");
+    /// assert_eq!(map.to_original(3), 17);
+    /// ```
+    pub fn remove_hbr_block_with_map(string: &str) -> (String, OffsetMap) {
+        filter_with_map(string, |c| c.is_hbr_block(), false)
+    }
+
+    /// Removes all Hebrew accents from the given string and returns an [`crate::OffsetMap`]
+    /// from the result back to the given string.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_accent_with_map;
+    ///
+    /// let (test_str_filtered, map) = remove_hbr_accent_with_map("בְּרֵאשִׁ֖ית");
+    /// assert_eq!(test_str_filtered, "בְּרֵאשִׁית");
+    /// assert_eq!(map.to_original(test_str_filtered.len()), "בְּרֵאשִׁ֖ית".len());
+    /// ```
+    pub fn remove_hbr_accent_with_map(string: &str) -> (String, OffsetMap) {
+        filter_with_map(string, |c| c.is_hbr_accent(), false)
+    }
+
+    /// Removes all Hebrew marks from the given string and returns an [`crate::OffsetMap`]
+    /// from the result back to the given string.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_mark_with_map;
+    ///
+    /// let (test_str_filtered, map) = remove_hbr_mark_with_map("ה֯ב");
+    /// assert_eq!(test_str_filtered, "הב");
+    /// assert_eq!(map.to_original(2), 4);
+    /// ```
+    pub fn remove_hbr_mark_with_map(string: &str) -> (String, OffsetMap) {
+        filter_with_map(string, |c| c.is_hbr_mark(), false)
+    }
+
+    /// Removes all Hebrew points from the given string and returns an [`crate::OffsetMap`]
+    /// from the result back to the given string.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_point_with_map;
+    ///
+    /// let (test_str_filtered, map) = remove_hbr_point_with_map("\u{05D1}\u{05BC}\u{05B8}\u{05E8}\u{05B8}\u{05D0}");
+    /// assert_eq!(test_str_filtered, "ברא");
+    /// assert_eq!(map.to_original(2), 6);
+    /// ```
+    pub fn remove_hbr_point_with_map(string: &str) -> (String, OffsetMap) {
+        filter_with_map(string, |c| c.is_hbr_point(), false)
+    }
+
+    /// Removes all Hebrew point vowels from the given string and returns an [`crate::OffsetMap`]
+    /// from the result back to the given string.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_point_vowel_with_map;
+    ///
+    /// let (test_str_filtered, map) = remove_hbr_point_vowel_with_map("\u{05D1}\u{05BC}\u{05B8}\u{05E8}\u{05B8}\u{05D0}");
+    /// assert_eq!(test_str_filtered, "\u{05D1}\u{05BC}\u{05E8}\u{05D0}");
+    /// assert_eq!(map.to_original(4), 6);
+    /// ```
+    pub fn remove_hbr_point_vowel_with_map(string: &str) -> (String, OffsetMap) {
+        filter_with_map(string, |c| c.is_hbr_point_vowel(), false)
+    }
+
+    /// Removes all Hebrew point semi-vowels from the given string and returns an [`crate::OffsetMap`]
+    /// from the result back to the given string.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_point_semi_vowel_with_map;
+    ///
+    /// let (test_str_filtered, map) = remove_hbr_point_semi_vowel_with_map("אֱלֹהִים");
+    /// assert_eq!(test_str_filtered, "אלֹהִים");
+    /// assert_eq!(map.to_original(2), 4);
+    /// ```
+    pub fn remove_hbr_point_semi_vowel_with_map(string: &str) -> (String, OffsetMap) {
+        filter_with_map(string, |c| c.is_hbr_point_semi_vowel(), false)
+    }
+
+    /// Removes all Hebrew point reading signs from the given string and returns an [`crate::OffsetMap`]
+    /// from the result back to the given string.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_point_reading_sign_with_map;
+    ///
+    /// let (test_str_filtered, map) = remove_hbr_point_reading_sign_with_map("\u{05D1}\u{05BC}\u{05B8}\u{05E8}\u{05B8}\u{05D0}");
+    /// assert_eq!(test_str_filtered, "\u{05D1}\u{05B8}\u{05E8}\u{05B8}\u{05D0}");
+    /// assert_eq!(map.to_original(2), 4);
+    /// ```
+    pub fn remove_hbr_point_reading_sign_with_map(string: &str) -> (String, OffsetMap) {
+        filter_with_map(string, |c| c.is_hbr_point_reading_sign(), false)
+    }
+
+    /// Removes all Hebrew punctuations from the given string and returns an [`crate::OffsetMap`]
+    /// from the result back to the given string.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_punctuation_with_map;
+    ///
+    /// let (test_str_filtered, map) = remove_hbr_punctuation_with_map("וַיְהִי־בֹקֶר");
+    /// assert_eq!(test_str_filtered, "וַיְהִיבֹקֶר");
+    /// assert_eq!(map.to_original(test_str_filtered.len()), "וַיְהִי־בֹקֶר".len());
+    /// ```
+    pub fn remove_hbr_punctuation_with_map(string: &str) -> (String, OffsetMap) {
+        filter_with_map(string, |c| c.is_hbr_punctuation(), false)
+    }
+
+    /// Removes all Hebrew letters (final and normal) from the given string and returns an [`crate::OffsetMap`]
+    /// from the result back to the given string.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_consonant_with_map;
+    ///
+    /// let (test_str_filtered, map) = remove_hbr_consonant_with_map("AאBףC");
+    /// assert_eq!(test_str_filtered, "ABC");
+    /// assert_eq!(map.to_original(1), 3);
+    /// ```
+    pub fn remove_hbr_consonant_with_map(string: &str) -> (String, OffsetMap) {
+        filter_with_map(string, |c| c.is_hbr_consonant(), false)
+    }
+
+    /// Removes all Hebrew normal letters from the given string and returns an [`crate::OffsetMap`]
+    /// from the result back to the given string.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_consonant_normal_with_map;
+    ///
+    /// let (test_str_filtered, map) = remove_hbr_consonant_normal_with_map("AאBףC");
+    /// assert_eq!(test_str_filtered, "ABףC");
+    /// assert_eq!(map.to_original(1), 3);
+    /// ```
+    pub fn remove_hbr_consonant_normal_with_map(string: &str) -> (String, OffsetMap) {
+        filter_with_map(string, |c| c.is_hbr_consonant_normal(), false)
+    }
+
+    /// Removes all Hebrew final letters from the given string and returns an [`crate::OffsetMap`]
+    /// from the result back to the given string.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_consonant_final_with_map;
+    ///
+    /// let (test_str_filtered, map) = remove_hbr_consonant_final_with_map("AאBףC");
+    /// assert_eq!(test_str_filtered, "AאBC");
+    /// assert_eq!(map.to_original(4), 6);
+    /// ```
+    pub fn remove_hbr_consonant_final_with_map(string: &str) -> (String, OffsetMap) {
+        filter_with_map(string, |c| c.is_hbr_consonant_final(), false)
+    }
+
+    /// Removes all Hebrew yod triangles from the given string and returns an [`crate::OffsetMap`]
+    /// from the result back to the given string.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_yod_triangle_with_map;
+    ///
+    /// let (test_str_filtered, map) = remove_hbr_yod_triangle_with_map("A\u{05EF}Z");
+    /// assert_eq!(test_str_filtered, "AZ");
+    /// assert_eq!(map.to_original(1), 3);
+    /// ```
+    pub fn remove_hbr_yod_triangle_with_map(string: &str) -> (String, OffsetMap) {
+        filter_with_map(string, |c| c.is_hbr_yod_triangle(), false)
+    }
+
+    /// Removes all Yiddish ligatures from the given string and returns an [`crate::OffsetMap`]
+    /// from the result back to the given string.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_ligature_yiddish_with_map;
+    ///
+    /// let (test_str_filtered, map) = remove_hbr_ligature_yiddish_with_map("XװױײZ");
+    /// assert_eq!(test_str_filtered, "XZ");
+    /// assert_eq!(map.to_original(1), 7);
+    /// ```
+    pub fn remove_hbr_ligature_yiddish_with_map(string: &str) -> (String, OffsetMap) {
+        filter_with_map(string, |c| c.is_hbr_ligature_yiddish(), false)
+    }
+}
+
+pub mod unicode_block_alphabetic_presentation_form {
+    use super::unicode_block_hebrew::*;
+    use crate::presentation_forms::with_decomposed_apf;
+    use std::borrow::Cow;
+
+    /// Removes all characters belonging to the unicode block 'Hebrew' from the given string,
+    /// after decomposing the Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_block_apf;
+    ///
+    /// let test_str = "a\u{FB31}b\u{FB4F}c";
+    /// let test_str_filtered = remove_hbr_block_apf(test_str);
+    /// assert_eq!(test_str_filtered.as_ref(), "abc");
+    /// ```
+    pub fn remove_hbr_block_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, remove_hbr_block)
+    }
+
+    /// Removes all Hebrew accents from the given string, after decomposing the Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_accent_apf;
+    ///
+    /// let test_str = "\u{FB31}\u{0596}";
+    /// let test_str_filtered = remove_hbr_accent_apf(test_str);
+    /// assert_eq!(test_str_filtered.as_ref(), "בּ");
+    /// ```
+    pub fn remove_hbr_accent_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, remove_hbr_accent)
+    }
+
+    /// Removes all Hebrew marks from the given string, after decomposing the Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_mark_apf;
+    ///
+    /// let test_str = "\u{FB31}\u{05AF}";
+    /// let test_str_filtered = remove_hbr_mark_apf(test_str);
+    /// assert_eq!(test_str_filtered.as_ref(), "בּ");
+    /// ```
+    pub fn remove_hbr_mark_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, remove_hbr_mark)
+    }
+
+    /// Removes all Hebrew points from the given string, including the points of Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_point_apf;
+    ///
+    /// let test_str = "\u{FB31}\u{FB2F}";
+    /// let test_str_filtered = remove_hbr_point_apf(test_str);
+    /// assert_eq!(test_str_filtered.as_ref(), "בא");
+    /// ```
+    pub fn remove_hbr_point_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, remove_hbr_point)
+    }
+
+    /// Removes all Hebrew point vowels from the given string, including those of Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_point_vowel_apf;
+    ///
+    /// let test_str = "\u{FB2F}\u{FB31}";
+    /// let test_str_filtered = remove_hbr_point_vowel_apf(test_str);
+    /// assert_eq!(test_str_filtered.as_ref(), "אבּ");
+    /// ```
+    pub fn remove_hbr_point_vowel_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, remove_hbr_point_vowel)
+    }
+
+    /// Removes all Hebrew point semi-vowels from the given string, after decomposing the Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_point_semi_vowel_apf;
+    ///
+    /// let test_str = "\u{FB3B}\u{05B0}";
+    /// let test_str_filtered = remove_hbr_point_semi_vowel_apf(test_str);
+    /// assert_eq!(test_str_filtered.as_ref(), "כּ");
+    /// ```
+    pub fn remove_hbr_point_semi_vowel_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, remove_hbr_point_semi_vowel)
+    }
+
+    /// Removes all Hebrew point reading signs from the given string, including those of Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_point_reading_sign_apf;
+    ///
+    /// let test_str = "\u{FB2C}\u{FB2F}";
+    /// let test_str_filtered = remove_hbr_point_reading_sign_apf(test_str);
+    /// assert_eq!(test_str_filtered.as_ref(), "שאָ");
+    /// ```
+    pub fn remove_hbr_point_reading_sign_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, remove_hbr_point_reading_sign)
+    }
+
+    /// Removes all Hebrew punctuations from the given string, after decomposing the Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_punctuation_apf;
+    ///
+    /// let test_str = "\u{FB31}\u{05BE}\u{FB4A}";
+    /// let test_str_filtered = remove_hbr_punctuation_apf(test_str);
+    /// assert_eq!(test_str_filtered.as_ref(), "בּתּ");
+    /// ```
+    pub fn remove_hbr_punctuation_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, remove_hbr_punctuation)
+    }
+
+    /// Removes all Hebrew letters (final and normal) from the given string, including Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_consonant_apf;
+    ///
+    /// let test_str = "A\u{FB21}B\u{FB26}C";
+    /// let test_str_filtered = remove_hbr_consonant_apf(test_str);
+    /// assert_eq!(test_str_filtered.as_ref(), "ABC");
+    /// ```
+    pub fn remove_hbr_consonant_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, remove_hbr_consonant)
+    }
+
+    /// Removes all Hebrew normal letters from the given string, including Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_consonant_normal_apf;
+    ///
+    /// let test_str = "A\u{FB21}B\u{FB26}C";
+    /// let test_str_filtered = remove_hbr_consonant_normal_apf(test_str);
+    /// assert_eq!(test_str_filtered.as_ref(), "ABםC");
+    /// ```
+    pub fn remove_hbr_consonant_normal_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, remove_hbr_consonant_normal)
+    }
+
+    /// Removes all Hebrew final letters from the given string, including Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_consonant_final_apf;
+    ///
+    /// let test_str = "A\u{FB21}B\u{FB26}C";
+    /// let test_str_filtered = remove_hbr_consonant_final_apf(test_str);
+    /// assert_eq!(test_str_filtered.as_ref(), "AאBC");
+    /// ```
+    pub fn remove_hbr_consonant_final_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, remove_hbr_consonant_final)
+    }
+
+    /// Removes all Hebrew yod triangles from the given string, after decomposing the Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_yod_triangle_apf;
+    ///
+    /// let test_str = format!("A{}\u{FB31}Z", '\u{05EF}');
+    /// let test_str_filtered = remove_hbr_yod_triangle_apf(&test_str);
+    /// assert_eq!(test_str_filtered.as_ref(), "AבּZ");
+    /// ```
+    pub fn remove_hbr_yod_triangle_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, remove_hbr_yod_triangle)
+    }
+
+    /// Removes all Yiddish ligatures from the given string, including Hebrew presentation forms.
+    ///
+    /// The patah of U+FB1F (HEBREW LIGATURE YIDDISH YOD YOD PATAH) is kept.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::remove_hbr_ligature_yiddish_apf;
+    ///
+    /// let test_str = "X\u{FB1F}Z";
+    /// let test_str_filtered = remove_hbr_ligature_yiddish_apf(test_str);
+    /// assert_eq!(test_str_filtered.as_ref(), "X\u{05B7}Z");
+    /// ```
+    pub fn remove_hbr_ligature_yiddish_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, remove_hbr_ligature_yiddish)
+    }
 }
 #[cfg(test)]
 mod unit_test {
@@ -261,6 +682,23 @@ mod unit_test {
         assert_eq!(test_str_filtered.as_ref(), "וַֽיְהִיבֹ֖קֶר");
     }
 
+    #[test]
+    pub fn rem_hbr_punctuation_with() {
+        let test_str = "ג׳ירפה׃ צה״ל ג’ירפה";
+        let options = PunctuationOptions {
+            geresh: true,
+            gershayim: false,
+            maqaf: false,
+            other: false,
+            look_alikes: true,
+        };
+        let test_str_filtered = remove_hbr_punctuation_with(test_str, &options);
+        assert_eq!(test_str_filtered.as_ref(), "גירפה׃ צה״ל גירפה");
+        let test_str_filtered =
+            remove_hbr_punctuation_with(test_str, &PunctuationOptions::default());
+        assert_eq!(test_str_filtered, remove_hbr_punctuation(test_str));
+    }
+
     #[test]
     pub fn rem_hbr_consonant() {
         let test_str = "AאBףC";
@@ -295,4 +733,19 @@ mod unit_test {
         let test_str_filtered = remove_hbr_ligature_yiddish(test_str);
         assert_eq!(test_str_filtered.as_ref(), "XZ");
     }
+
+    #[test]
+    pub fn rem_hbr_point_apf() {
+        let test_str = "\u{FB2C}\u{FB2F}\u{FB4B}";
+        assert_eq!(remove_hbr_point(test_str).as_ref(), test_str);
+        let test_str_filtered = remove_hbr_point_apf(test_str);
+        assert_eq!(test_str_filtered.as_ref(), "שאו");
+    }
+
+    #[test]
+    pub fn rem_hbr_block_apf() {
+        let test_str = "X\u{FB2C}\u{FB21}\u{FB1E}Z";
+        let test_str_filtered = remove_hbr_block_apf(test_str);
+        assert_eq!(test_str_filtered.as_ref(), "X\u{FB1E}Z");
+    }
 }
@@ -0,0 +1,312 @@
+pub mod unicode_block_hebrew {
+    use crate::punctuation::{hebrew_punctuation, is_letter, is_maqaf_like};
+    use crate::repair::{accepts_marks, Unit};
+    use crate::validation::combining_class;
+    use crate::{get_hbr_clusters, get_hbr_vowels, VowelKind};
+    use std::borrow::Cow;
+    use std::ops::Range;
+
+    /// The options for [`repair_hbr`], every repair can be switched off.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct RepairOptions {
+        /// Put the points, accents and marks of every letter in canonical order
+        pub canonical_order: bool,
+        /// Remove a point, accent or mark that occurs twice on one letter
+        pub duplicate_marks: bool,
+        /// Move points, accents and marks without a letter onto the preceding letter of the same word
+        pub orphan_marks: bool,
+        /// Replace the holam on a consonantal vav by holam haser for vav (U+05BA)
+        pub holam_haser_for_vav: bool,
+        /// Replace the look-alikes of geresh, gershayim and maqaf in Hebrew words
+        pub punctuation: bool,
+    }
+
+    impl Default for RepairOptions {
+        /// All repairs.
+        fn default() -> Self {
+            RepairOptions {
+                canonical_order: true,
+                duplicate_marks: true,
+                orphan_marks: true,
+                holam_haser_for_vav: true,
+                punctuation: true,
+            }
+        }
+    }
+
+    /// The kind of change made by [`repair_hbr`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum RepairKind {
+        /// The marks of a letter were put in canonical order
+        NonCanonicalOrder,
+        /// A duplicated mark was removed
+        DuplicateMark,
+        /// Marks without a letter were moved onto the preceding letter
+        OrphanMark,
+        /// A holam on a consonantal vav was replaced by holam haser for vav
+        HolamHaserForVav,
+        /// A look-alike was replaced by a geresh
+        Geresh,
+        /// A look-alike was replaced by a gershayim
+        Gershayim,
+        /// A look-alike was replaced by a maqaf
+        Maqaf,
+    }
+
+    /// A change made by [`repair_hbr`].
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct HebrewRepair {
+        /// The byte offsets of the changed characters in the original string
+        pub span: Range<usize>,
+        pub kind: RepairKind,
+    }
+
+    /// Repairs the mechanically fixable problems reported by [`crate::validate_hbr`],
+    /// and returns the repaired string with the changes made, sorted by span.
+    ///
+    /// - marks are put in canonical order and duplicated marks are removed
+    /// - marks without a letter are moved onto the preceding letter of the same word (words end at
+    ///   whitespace, maqaf and hyphens),
+    ///   unless that letter would get multiple vowels or a final letter a vowel
+    /// - a holam on a consonantal vav becomes holam haser for vav (U+05BA)
+    /// - look-alikes of geresh, gershayim and maqaf in Hebrew words are replaced,
+    ///   see [`crate::normalize_hbr_punctuation`]
+    ///
+    /// Multiple vowels, shin dots on other letters and vowels on final letters are not repaired,
+    /// as the intended text cannot be known.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{repair_hbr, RepairKind, RepairOptions};
+    ///
+    /// let (repaired, changes) = repair_hbr("צה\"ל בּ\u{05B0}ּ", &RepairOptions::default());
+    /// assert_eq!(repaired, "צה״ל בְּ");
+    /// let kinds: Vec<RepairKind> = changes.iter().map(|change| change.kind).collect();
+    /// assert_eq!(
+    ///     kinds,
+    ///     vec![RepairKind::Gershayim, RepairKind::NonCanonicalOrder, RepairKind::DuplicateMark]
+    /// );
+    /// assert_eq!(changes[2].span, 14..16);
+    /// ```
+    pub fn repair_hbr<'a>(
+        text: &'a str,
+        options: &RepairOptions,
+    ) -> (Cow<'a, str>, Vec<HebrewRepair>) {
+        let mut changes: Vec<HebrewRepair> = Vec::new();
+        let mut change =
+            |span: Range<usize>, kind: RepairKind| changes.push(HebrewRepair { span, kind });
+        let vowels = get_hbr_vowels(text);
+        let mut next_vowel = 0;
+        let mut units: Vec<Unit> = Vec::new();
+
+        for cluster in get_hbr_clusters(text) {
+            let base = cluster.base();
+            let marks_start = cluster.span.start + base.map_or(0, char::len_utf8);
+            let mut marks: Vec<(char, Range<usize>)> = cluster.text
+                [marks_start - cluster.span.start..]
+                .char_indices()
+                .map(|(pos, c)| (c, marks_start + pos..marks_start + pos + c.len_utf8()))
+                .collect();
+            let holam_haser = match vowels.get(next_vowel) {
+                Some(analysis) if analysis.cluster.span == cluster.span => {
+                    next_vowel += 1;
+                    analysis
+                        .vowel
+                        .map_or(false, |vowel| vowel.kind == VowelKind::HolamHaser)
+                }
+                _ => false,
+            };
+
+            // whitespace, maqaf and hyphens separate words
+            let is_boundary = |c: char| c.is_whitespace() || c == '\u{05BE}' || is_maqaf_like(c);
+            if options.orphan_marks
+                && !marks.is_empty()
+                && !base.map_or(false, is_letter)
+                && !base.map_or(false, is_boundary)
+            {
+                let target = units
+                    .iter_mut()
+                    .rev()
+                    .take_while(|unit| !unit.base.map_or(true, is_boundary))
+                    .find(|unit| unit.base.map_or(false, is_letter))
+                    .filter(|unit| accepts_marks(unit, &marks));
+                if let Some(target) = target {
+                    change(marks_start..cluster.span.end, RepairKind::OrphanMark);
+                    target.marks.append(&mut marks);
+                }
+            }
+            if options.holam_haser_for_vav && holam_haser && base == Some('ו') {
+                for mark in marks.iter_mut().filter(|(c, _)| *c == '\u{05B9}') {
+                    mark.0 = '\u{05BA}';
+                    change(mark.1.clone(), RepairKind::HolamHaserForVav);
+                }
+            }
+            units.push(Unit {
+                base,
+                base_span: cluster.span.start..marks_start,
+                marks,
+            });
+        }
+
+        for unit in units.iter_mut() {
+            if options.duplicate_marks {
+                let mut seen: Vec<char> = Vec::with_capacity(unit.marks.len());
+                unit.marks.retain(|(c, span)| {
+                    if seen.contains(c) {
+                        change(span.clone(), RepairKind::DuplicateMark);
+                        return false;
+                    }
+                    seen.push(*c);
+                    true
+                });
+            }
+            if options.canonical_order
+                && unit
+                    .marks
+                    .windows(2)
+                    .any(|pair| combining_class(pair[0].0) > combining_class(pair[1].0))
+            {
+                let start = unit
+                    .marks
+                    .iter()
+                    .map(|(_, span)| span.start)
+                    .min()
+                    .unwrap_or(0);
+                let end = unit
+                    .marks
+                    .iter()
+                    .map(|(_, span)| span.end)
+                    .max()
+                    .unwrap_or(0);
+                unit.marks.sort_by_key(|(c, _)| combining_class(*c));
+                change(start..end, RepairKind::NonCanonicalOrder);
+            }
+        }
+
+        if options.punctuation {
+            for (span, punctuation) in hebrew_punctuation(text) {
+                let kind = match punctuation {
+                    '\u{05F3}' => RepairKind::Geresh,
+                    '\u{05F4}' => RepairKind::Gershayim,
+                    _ => RepairKind::Maqaf,
+                };
+                if let Ok(i) = units.binary_search_by_key(&span.start, |unit| unit.base_span.start)
+                {
+                    units[i].base = Some(punctuation);
+                    change(span, kind);
+                }
+            }
+        }
+
+        if changes.is_empty() {
+            return (Cow::Borrowed(text), changes);
+        }
+        let repaired: String = units
+            .iter()
+            .flat_map(|unit| {
+                unit.base
+                    .into_iter()
+                    .chain(unit.marks.iter().map(|(c, _)| *c))
+            })
+            .collect();
+        changes.sort_by_key(|change| (change.span.start, change.span.end));
+        (Cow::Owned(repaired), changes)
+    }
+}
+
+use crate::presentation_forms::decompose_apf_char;
+use hebrew_unicode_script::HebrewUnicodeScript;
+use std::ops::Range;
+
+/// Checks if the given marks can be moved onto the letter of the unit
+/// without giving it multiple vowels or giving a final letter a vowel.
+pub(crate) fn accepts_marks(unit: &Unit, marks: &[(char, Range<usize>)]) -> bool {
+    let base = match unit.base {
+        Some(base) => base,
+        None => return false,
+    };
+    let decomposed = decompose_apf_char(base).unwrap_or_default();
+    let letter = decomposed.chars().next().unwrap_or(base);
+    let is_vowel = |c: &char| c.is_hbr_point_vowel() || c.is_hbr_point_semi_vowel();
+    let vowels: Vec<char> = decomposed
+        .chars()
+        .skip(1)
+        .chain(unit.marks.iter().map(|(c, _)| *c))
+        .chain(marks.iter().map(|(c, _)| *c))
+        .filter(is_vowel)
+        .collect();
+    match vowels.as_slice() {
+        [] => true,
+        [vowel] => {
+            !letter.is_hbr_consonant_final()
+                || (letter == 'ך' && matches!(vowel, '\u{05B0}' | '\u{05B8}'))
+        }
+        _ => false,
+    }
+}
+
+/// A base character with the marks it carries after repair, each with its span in the original string.
+pub(crate) struct Unit {
+    pub(crate) base: Option<char>,
+    pub(crate) base_span: Range<usize>,
+    pub(crate) marks: Vec<(char, Range<usize>)>,
+}
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+
+    #[test]
+    fn repair_marks() {
+        let options = RepairOptions::default();
+        let (repaired, changes) = repair_hbr("צה\"ָל", &options);
+        assert_eq!(repaired, "צהָ\"ל");
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].kind, RepairKind::OrphanMark);
+        assert_eq!(changes[0].span, 5..7);
+        assert!(validate_hbr(&repaired).is_empty());
+        // across whitespace, hyphens and maqaf, onto a final letter or a second vowel
+        // the mark is left alone
+        for text in [
+            "שָׁלוֹם ָעוֹלָם",
+            "ב ָית",
+            "ב-\u{05B8}ית",
+            "ב\u{05BE}\u{05B8}ית",
+            "ב\u{2010}\u{05B8}ית",
+            "צהָ\"ִל",
+            "שָׁלוֹם\"ָעוֹלָם",
+        ] {
+            let (repaired, changes) = repair_hbr(text, &options);
+            assert_eq!(repaired, text);
+            assert!(changes.is_empty());
+        }
+        let (repaired, changes) = repair_hbr("מִצְוֹת", &options);
+        assert_eq!(repaired, "מִצְוֺת");
+        assert_eq!(changes[0].kind, RepairKind::HolamHaserForVav);
+        let (repaired, changes) = repair_hbr("שָׁלוֹם", &options);
+        assert!(matches!(repaired, std::borrow::Cow::Borrowed(_)));
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn repair_punctuation() {
+        let options = RepairOptions::default();
+        let (repaired, _) = repair_hbr("ג'ירפה, ש' וגם 'שלום' תל-אביב a-b", &options);
+        assert_eq!(repaired, "ג׳ירפה, ש׳ וגם 'שלום' תל־אביב a-b");
+        let options = RepairOptions {
+            punctuation: false,
+            ..RepairOptions::default()
+        };
+        let (repaired, changes) = repair_hbr("תל-אביב", &options);
+        assert_eq!(repaired, "תל-אביב");
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn repaired_text_validates() {
+        let broken = "בּ\u{05B0}ּרֵאשִׁית צה\"ָל";
+        assert!(!validate_hbr(broken).is_empty());
+        let (repaired, _) = repair_hbr(broken, &RepairOptions::default());
+        assert!(validate_hbr(&repaired).is_empty());
+    }
+}
@@ -0,0 +1,462 @@
+pub mod unicode_block_hebrew {
+    use crate::roots::{add_evidence, cores, match_template, metathesis_fits, reconstruct};
+    use crate::roots::{root_letter_weight, weaknesses, STRONG_TEMPLATES, WEAK_TEMPLATES};
+    use crate::{get_hbr_vowels, segment_hbr_word, to_final_forms, DageshKind, HebrewPattern};
+    use hebrew_unicode_script::HebrewUnicodeScript;
+
+    /// A weak letter of a Hebrew root, which disappears or changes in some forms.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum RootWeakness {
+        /// פ״נ: the first root letter is a nun, often assimilated as in יִפֹּל from נפל
+        InitialNun,
+        /// פ״י: the first root letter is a yod (originally often a vav), as in יֵשֵׁב and הוֹשִׁיב from ישב
+        InitialYod,
+        /// ע״ו or ע״י: the middle root letter is a vav or yod, as in קָם from קום
+        MiddleVavYod,
+        /// ל״ה: the last root letter is a he, as in בָּנוּ from בנה
+        FinalHe,
+        /// ע״ע: the second and third root letter are the same, as in סַבּוּ from סבב
+        Geminate,
+    }
+
+    /// A possible root of a Hebrew word, see [`extract_hbr_root`].
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct RootCandidate {
+        /// The three root letters without points, ending in a final letter where needed
+        pub root: String,
+        /// The weak letters of the root, empty for a strong root
+        pub weaknesses: Vec<RootWeakness>,
+        /// The share of the evidence for this root among all candidates of the word, between 0 and 1;
+        /// the confidences of the candidates of a word add up to 1
+        pub confidence: f64,
+    }
+
+    /// Proposes the three letter roots of a Hebrew word, the most plausible first.
+    ///
+    /// The prefixes and pronominal suffix are split off by [`crate::segment_hbr_word`], then endings
+    /// such as ים, ות and תי, and finally the letters of a binyan or mishkal, such as the מ of מִכְתָּב
+    /// or the הִתְ of הִתְכַּתֵּב. When only two root letters remain, the missing weak letter is restored:
+    /// an assimilated nun, a yod, a middle vav or yod, a final he or a repeated last letter.
+    ///
+    /// In pointed text the vowels, as analysed by [`crate::get_hbr_vowels`], point to the right root:
+    /// a dagesh forte after a prefix reveals an assimilated nun, a dagesh forte in the last letter
+    /// a geminate root, and a qamats before a dropped ending he a III-he root. In unpointed text
+    /// every reading is a candidate, weighed by how common it is. The weights are normalised per word,
+    /// so a confidence of 0.6 means the root has 60% of the evidence, whichever word it was found in.
+    ///
+    /// A word with other characters than Hebrew letters and points has no candidates.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{extract_hbr_root, RootWeakness};
+    ///
+    /// let candidates = extract_hbr_root("מִכְתָּבִים");
+    /// assert_eq!(candidates[0].root, "כתב");
+    /// assert_eq!(candidates[0].confidence, 1.0);
+    /// assert_eq!(extract_hbr_root("והתכתבנו")[0].root, "כתב");
+    ///
+    /// let candidates = extract_hbr_root("וַיִּפֹּל");
+    /// assert_eq!(candidates[0].root, "נפל");
+    /// assert_eq!(candidates[0].weaknesses, vec![RootWeakness::InitialNun]);
+    ///
+    /// assert_eq!(extract_hbr_root("קָם")[0].root, "קום");
+    /// assert!(extract_hbr_root("abc").is_empty());
+    /// ```
+    pub fn extract_hbr_root(word: &str) -> Vec<RootCandidate> {
+        let pointed = word
+            .chars()
+            .any(|c| c.is_hbr_point_vowel() || c.is_hbr_point_semi_vowel());
+        let strong: Vec<(&str, HebrewPattern, f64)> = STRONG_TEMPLATES
+            .iter()
+            .filter_map(|&(t, weight)| HebrewPattern::new(t).ok().map(|p| (t, p, weight)))
+            .collect();
+        let weak: Vec<(&str, HebrewPattern, f64, Option<RootWeakness>)> = WEAK_TEMPLATES
+            .iter()
+            .filter_map(|&(t, weight, known)| {
+                HebrewPattern::new(t).ok().map(|p| (t, p, weight, known))
+            })
+            .collect();
+
+        let segmentations = segment_hbr_word(word);
+        let best = segmentations.first().map_or(0.0, |s| s.score);
+        let mut evidence: Vec<([char; 3], f64)> = Vec::new();
+        for segmentation in segmentations.iter().take(3) {
+            if best <= 0.0 {
+                break;
+            }
+            let stem = &word[segmentation.stem_span.clone()];
+            for core in cores(stem, pointed) {
+                let vowels = get_hbr_vowels(core.text);
+                let forte = |i: usize| {
+                    pointed && vowels.get(i).and_then(|v| v.dagesh) == Some(DageshKind::Forte)
+                };
+                let weight = segmentation.score / best * core.weight;
+                // a dagesh forte after a first letter that can be a prefix points to an assimilated nun
+                let assimilated = forte(1)
+                    && vowels[0].vowel.is_some()
+                    && vowels[0]
+                        .cluster
+                        .base()
+                        .map_or(false, |c| "איתנמה".contains(c));
+
+                for (template, pattern, template_weight) in &strong {
+                    if let Some((root, _)) = match_template(template, pattern, core.text) {
+                        let root = [root[0], root[1], root[2]];
+                        if !metathesis_fits(template, root[0]) {
+                            continue;
+                        }
+                        let mut w = weight * template_weight * root_letter_weight(&root);
+                        if assimilated && template.starts_with('1') {
+                            w *= 0.4;
+                        }
+                        add_evidence(&mut evidence, root, w);
+                    }
+                }
+                for (template, pattern, template_weight, known) in &weak {
+                    if let Some((root, positions)) = match_template(template, pattern, core.text) {
+                        let first = vowels[positions[0]].vowel.map(|v| v.point);
+                        let cues = (
+                            forte(positions[0]) && positions[0] > 0,
+                            forte(positions[1]),
+                            pointed && matches!(first, Some('\u{05B8}')) && core.ending.is_none(),
+                        );
+                        for (root, w) in reconstruct(root[0], root[1], *known, &core, cues) {
+                            let w = weight * template_weight * w * root_letter_weight(&root);
+                            add_evidence(&mut evidence, root, w);
+                        }
+                    }
+                }
+            }
+        }
+
+        let total: f64 = evidence.iter().map(|(_, weight)| weight).sum();
+        evidence.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(a.0.cmp(&b.0))
+        });
+        evidence
+            .into_iter()
+            .map(|(root, confidence)| RootCandidate {
+                root: to_final_forms(&root.iter().collect::<String>()).into_owned(),
+                weaknesses: weaknesses(&root),
+                confidence: confidence / total,
+            })
+            .collect()
+    }
+}
+
+use crate::final_forms::to_normal_form;
+use crate::segmentation::letters;
+use crate::{HebrewPattern, RootWeakness};
+
+/// Verb and noun endings that are not pronominal suffixes, longest first.
+const ENDINGS: [&str; 11] = ["ים", "ות", "תי", "תם", "תן", "נו", "ית", "ה", "ת", "ו", "י"];
+
+/// Binyan and mishkal templates with the root letters 1, 2 and 3, and how common they are.
+pub(crate) const STRONG_TEMPLATES: [(&str, f64); 34] = [
+    ("123", 0.9),
+    ("1ו23", 0.8),
+    ("12ו3", 0.7),
+    ("12י3", 0.6),
+    ("1י23", 0.4),
+    ("מ123", 0.7),
+    ("מ12י3", 0.6),
+    ("מ12ו3", 0.5),
+    ("ת123", 0.6),
+    ("ת12י3", 0.6),
+    ("ת12ו3", 0.5),
+    ("י123", 0.7),
+    ("י12י3", 0.6),
+    ("א123", 0.5),
+    ("א12י3", 0.4),
+    ("נ123", 0.6),
+    ("נ12י3", 0.4),
+    ("ה123", 0.5),
+    ("ה12י3", 0.7),
+    ("הת123", 0.8),
+    ("מת123", 0.8),
+    ("ית123", 0.7),
+    ("תת123", 0.3),
+    ("נת123", 0.5),
+    ("את123", 0.5),
+    // hitpael with a sibilant first root letter, which swaps places with the ת (and voices it)
+    ("ה1ת23", 0.7),
+    ("מ1ת23", 0.7),
+    ("י1ת23", 0.6),
+    ("ה1ד23", 0.7),
+    ("מ1ד23", 0.7),
+    ("י1ד23", 0.6),
+    ("ה1ט23", 0.7),
+    ("מ1ט23", 0.7),
+    ("י1ט23", 0.6),
+];
+
+/// Templates of forms that lost a weak root letter, with the remaining root letters 1 and 2,
+/// how common they are and the weak letter they are known to have lost.
+pub(crate) const WEAK_TEMPLATES: [(&str, f64, Option<RootWeakness>); 14] = [
+    ("12", 0.9, None),
+    ("י12", 0.7, None),
+    ("ת12", 0.6, None),
+    ("א12", 0.5, None),
+    ("נ12", 0.5, None),
+    ("מ12", 0.5, None),
+    ("ה1י2", 0.6, None),
+    ("מ1י2", 0.5, None),
+    ("י1י2", 0.5, None),
+    ("הו12", 0.6, Some(RootWeakness::InitialYod)),
+    ("הו1י2", 0.8, Some(RootWeakness::InitialYod)),
+    ("מו1י2", 0.7, Some(RootWeakness::InitialYod)),
+    ("נו12", 0.6, Some(RootWeakness::InitialYod)),
+    ("יו1י2", 0.6, Some(RootWeakness::InitialYod)),
+];
+
+/// A stem with or without one of the [`ENDINGS`].
+pub(crate) struct Core<'a> {
+    pub(crate) text: &'a str,
+    pub(crate) ending: Option<&'static str>,
+    /// The ending is a he after a qamats, segol or tsere, as in בָּנָה and בּוֹנֶה
+    pub(crate) final_he: bool,
+    pub(crate) weight: f64,
+}
+
+/// Returns the stem and the stems without an ending, keeping at least two letters.
+pub(crate) fn cores(stem: &str, pointed: bool) -> Vec<Core<'_>> {
+    let letters = match letters(stem) {
+        Some(letters) if letters.len() >= 2 => letters,
+        _ => return Vec::new(),
+    };
+    let mut cores = vec![Core {
+        text: stem,
+        ending: None,
+        final_he: false,
+        weight: 1.0,
+    }];
+    for ending in ENDINGS {
+        let length = ending.chars().count();
+        if letters.len() < length + 2 {
+            continue;
+        }
+        let tail = &letters[letters.len() - length..];
+        if !tail
+            .iter()
+            .map(|l| l.normal)
+            .eq(ending.chars().map(to_normal_form))
+        {
+            continue;
+        }
+        // a he with mappiq is a consonant, not an ending
+        if ending == "ה" && tail[0].dagesh {
+            continue;
+        }
+        let before = &letters[letters.len() - length - 1];
+        cores.push(Core {
+            text: &stem[..tail[0].span.start],
+            ending: Some(ending),
+            final_he: pointed
+                && ending == "ה"
+                && before
+                    .vowel
+                    .map_or(false, |v| "\u{05B5}\u{05B6}\u{05B8}".contains(v)),
+            weight: 0.6,
+        });
+    }
+    cores
+}
+
+/// Matches a template against a whole core, returning the root letters and their positions.
+pub(crate) fn match_template(
+    template: &str,
+    pattern: &HebrewPattern,
+    core: &str,
+) -> Option<(Vec<char>, Vec<usize>)> {
+    if !pattern.matches(core) {
+        return None;
+    }
+    let root: Vec<char> = pattern.find_all(core).first()?.root.chars().collect();
+    let positions = template
+        .chars()
+        .enumerate()
+        .filter(|(_, c)| c.is_ascii_digit())
+        .map(|(i, _)| i)
+        .collect();
+    Some((root, positions))
+}
+
+/// Checks that the first root letter of a hitpael template with metathesis is a sibilant:
+/// ש or ס before ת, ז before ד and צ before ט.
+pub(crate) fn metathesis_fits(template: &str, first: char) -> bool {
+    if template.contains("1ת") {
+        first == 'ש' || first == 'ס'
+    } else if template.contains("1ד") {
+        first == 'ז'
+    } else if template.contains("1ט") {
+        first == 'צ'
+    } else {
+        true
+    }
+}
+
+/// The root letters that can be lost, with how often a word with two remaining letters
+/// lost each of them; the cues are a dagesh forte in the first and in the second remaining letter
+/// and a qamats under the first.
+pub(crate) fn reconstruct(
+    first: char,
+    second: char,
+    known: Option<RootWeakness>,
+    core: &Core<'_>,
+    cues: (bool, bool, bool),
+) -> Vec<([char; 3], f64)> {
+    if known == Some(RootWeakness::InitialYod) {
+        return vec![(['י', first, second], 0.8)];
+    }
+    let (first_forte, second_forte, qamats) = cues;
+    let final_he = if core.final_he {
+        0.8
+    } else if core.ending.is_some() {
+        0.5
+    } else {
+        0.35
+    };
+    let mut options = vec![
+        (['נ', first, second], if first_forte { 0.8 } else { 0.3 }),
+        (['י', first, second], 0.25),
+        ([first, 'ו', second], if qamats { 0.6 } else { 0.4 }),
+        ([first, 'י', second], 0.2),
+        ([first, second, 'ה'], final_he),
+        (
+            [first, second, second],
+            if second_forte { 0.7 } else { 0.25 },
+        ),
+    ];
+    // a dagesh forte already explains which letter is missing
+    if first_forte || second_forte {
+        for (root, weight) in options.iter_mut() {
+            let explained = (first_forte && root[0] == 'נ')
+                || (second_forte && root[1] == first && root[2] == second && root[1] != root[2])
+                || (second_forte && root[1] == root[2] && root[2] == second);
+            if !explained {
+                *weight *= 0.5;
+            }
+        }
+    }
+    options
+}
+
+/// Roots with a first or last vav are rare: they merged with the I-yod and III-he roots.
+pub(crate) fn root_letter_weight(root: &[char; 3]) -> f64 {
+    if root[0] == 'ו' || root[2] == 'ו' {
+        0.2
+    } else {
+        1.0
+    }
+}
+
+/// Combines independent evidence for a root.
+pub(crate) fn add_evidence(evidence: &mut Vec<([char; 3], f64)>, root: [char; 3], weight: f64) {
+    match evidence.iter_mut().find(|(r, _)| *r == root) {
+        Some((_, confidence)) => *confidence = 1.0 - (1.0 - *confidence) * (1.0 - weight),
+        None => evidence.push((root, weight)),
+    }
+}
+
+/// The weak letters of a root in normal form.
+pub(crate) fn weaknesses(root: &[char; 3]) -> Vec<RootWeakness> {
+    let mut weaknesses = Vec::new();
+    if root[0] == 'נ' {
+        weaknesses.push(RootWeakness::InitialNun);
+    }
+    if root[0] == 'י' {
+        weaknesses.push(RootWeakness::InitialYod);
+    }
+    if root[1] == 'ו' || root[1] == 'י' {
+        weaknesses.push(RootWeakness::MiddleVavYod);
+    }
+    if root[2] == 'ה' {
+        weaknesses.push(RootWeakness::FinalHe);
+    }
+    if root[1] == root[2] {
+        weaknesses.push(RootWeakness::Geminate);
+    }
+    weaknesses
+}
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+
+    fn top(word: &str) -> String {
+        extract_hbr_root(word)
+            .into_iter()
+            .next()
+            .map(|c| c.root)
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn root_strong_pointed_and_unpointed() {
+        assert_eq!(top("כּוֹתֵב"), "כתב");
+        assert_eq!(top("כותב"), "כתב");
+        assert_eq!(top("מכתבים"), "כתב");
+        assert_eq!(top("הִתְכַּתַּבְנוּ"), "כתב");
+        assert_eq!(top("הִשְׁתַּמֵּשׁ"), "שמש");
+        let candidates = extract_hbr_root("שלום");
+        assert!(candidates
+            .iter()
+            .all(|c| c.confidence > 0.0 && c.confidence <= 1.0));
+        assert!(candidates
+            .windows(2)
+            .all(|w| w[0].confidence >= w[1].confidence));
+        let total: f64 = candidates.iter().map(|c| c.confidence).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn root_confidence_threshold() {
+        for (word, root) in [
+            ("מִכְתָּבִים", "כתב"),
+            ("והתכתבנו", "כתב"),
+            ("וַיִּפֹּל", "נפל"),
+            ("קָם", "קום"),
+            ("הוֹשִׁיבוּ", "ישב"),
+        ] {
+            let candidates = extract_hbr_root(word);
+            assert_eq!(candidates[0].root, root);
+            assert!(candidates[0].confidence >= 0.3, "{}", word);
+        }
+        let candidates = extract_hbr_root("הוֹשִׁיבוּ");
+        assert!(candidates[0].confidence >= 0.6);
+        assert!(candidates[1].confidence < 0.4);
+    }
+
+    #[test]
+    fn root_weak_letters() {
+        assert_eq!(top("יִפֹּל"), "נפל");
+        assert_eq!(top("הוֹשִׁיב"), "ישב");
+        assert_eq!(top("סַבּוּ"), "סבב");
+        assert_eq!(top("בָּנָה"), "בנה");
+        assert_eq!(top("קם"), "קום");
+        let candidates = extract_hbr_root("סַבּוּ");
+        assert_eq!(candidates[0].weaknesses, vec![RootWeakness::Geminate]);
+        let candidates = extract_hbr_root("בָּנָה");
+        assert_eq!(candidates[0].weaknesses, vec![RootWeakness::FinalHe]);
+    }
+
+    #[test]
+    fn root_templates_compile() {
+        use crate::roots::{STRONG_TEMPLATES, WEAK_TEMPLATES};
+        for (template, _) in STRONG_TEMPLATES {
+            assert!(HebrewPattern::new(template).is_ok(), "{}", template);
+        }
+        for (template, _, _) in WEAK_TEMPLATES {
+            assert!(HebrewPattern::new(template).is_ok(), "{}", template);
+        }
+    }
+
+    #[test]
+    fn root_no_candidates() {
+        assert!(extract_hbr_root("").is_empty());
+        assert!(extract_hbr_root("a-b").is_empty());
+        assert!(extract_hbr_root("ב").is_empty());
+    }
+}
@@ -0,0 +1,191 @@
+pub mod unicode_block_hebrew {
+    use crate::clusters::is_hbr_combining;
+    use crate::ordering::{bidi_class, resolve_levels, BidiClass};
+    use hebrew_unicode_script::HebrewUnicodeScript;
+    use std::ops::Range;
+
+    /// The script of a run of text.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Script {
+        /// Characters of the blocks 'Hebrew' and 'Alphabetic Presentation Forms'
+        Hebrew,
+        /// Latin letters
+        Latin,
+        /// Digits
+        Digit,
+        /// Spaces, punctuation and symbols
+        Neutral,
+        /// Letters of any other script
+        Other,
+    }
+
+    /// The resolved direction of a run of text.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Direction {
+        LeftToRight,
+        RightToLeft,
+    }
+
+    /// A contiguous part of a text with one script and one direction.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ScriptRun {
+        /// The byte offsets of the run within the analysed string
+        pub span: Range<usize>,
+        pub script: Script,
+        pub direction: Direction,
+    }
+
+    /// Splits the given string into runs of Hebrew, Latin, digits, neutral characters and other scripts.
+    ///
+    /// Points, accents, marks and other combining characters belong to the run of their base character.
+    /// The direction of every run is resolved with a lightweight subset of the Unicode Bidirectional Algorithm (UAX #9),
+    /// without explicit embeddings; the paragraph direction is taken from the first strong character of each line.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{hbr_script_runs, Direction, Script};
+    ///
+    /// let runs = hbr_script_runs("שָׁלוֹם, world 2024!");
+    /// let labels: Vec<(Script, Direction)> = runs.iter().map(|run| (run.script, run.direction)).collect();
+    /// assert_eq!(labels, vec![
+    ///     (Script::Hebrew, Direction::RightToLeft),
+    ///     (Script::Neutral, Direction::RightToLeft),
+    ///     (Script::Latin, Direction::LeftToRight),
+    ///     (Script::Neutral, Direction::LeftToRight),
+    ///     (Script::Digit, Direction::LeftToRight),
+    ///     (Script::Neutral, Direction::RightToLeft),
+    /// ]);
+    /// assert_eq!(runs[0].span, 0..14);
+    /// ```
+    pub fn hbr_script_runs(text: &str) -> Vec<ScriptRun> {
+        let mut runs: Vec<ScriptRun> = Vec::new();
+        let mut offset = 0;
+        for paragraph in text.split_inclusive('\n') {
+            // (base character, span) of every base character with its combining characters
+            let mut units: Vec<(char, Range<usize>)> = Vec::new();
+            for (pos, c) in paragraph.char_indices() {
+                let pos = offset + pos;
+                match units.last_mut() {
+                    Some(unit) if is_combining(c) => unit.1.end = pos + c.len_utf8(),
+                    _ => units.push((c, pos..pos + c.len_utf8())),
+                }
+            }
+            offset += paragraph.len();
+
+            let rtl_paragraph = paragraph
+                .chars()
+                .map(bidi_class)
+                .find(|&class| class == BidiClass::L || class == BidiClass::R)
+                == Some(BidiClass::R);
+            let bases: Vec<char> = units.iter().map(|(c, _)| *c).collect();
+            let levels = resolve_levels(&bases, rtl_paragraph);
+            for ((base, span), level) in units.into_iter().zip(levels) {
+                let script = script(base);
+                let direction = if level % 2 == 1 {
+                    Direction::RightToLeft
+                } else {
+                    Direction::LeftToRight
+                };
+                match runs.last_mut() {
+                    Some(run)
+                        if run.script == script
+                            && run.direction == direction
+                            && run.span.end == span.start =>
+                    {
+                        run.span.end = span.end
+                    }
+                    _ => runs.push(ScriptRun {
+                        span,
+                        script,
+                        direction,
+                    }),
+                }
+            }
+        }
+        runs
+    }
+
+    fn script(c: char) -> Script {
+        if c.is_hbr_block() || c.is_apf_block() {
+            Script::Hebrew
+        } else if c.is_numeric() {
+            Script::Digit
+        } else if c.is_alphabetic() && (c <= '\u{024F}' || ('\u{1E00}'..='\u{1EFF}').contains(&c)) {
+            Script::Latin
+        } else if c.is_alphabetic() {
+            Script::Other
+        } else {
+            Script::Neutral
+        }
+    }
+
+    fn is_combining(c: char) -> bool {
+        match c {
+            '\u{0300}'..='\u{036F}' | '\u{1AB0}'..='\u{1AFF}' => true,
+            '\u{20D0}'..='\u{20FF}' | '\u{FE20}'..='\u{FE2F}' => true,
+            _ => is_hbr_combining(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+
+    fn labels(text: &str) -> Vec<(&str, Script, Direction)> {
+        hbr_script_runs(text)
+            .into_iter()
+            .map(|run| (&text[run.span], run.script, run.direction))
+            .collect()
+    }
+
+    #[test]
+    fn script_runs_left_to_right_paragraph() {
+        use Direction::*;
+        assert_eq!(
+            labels("Call שלום עולם at 10:30"),
+            vec![
+                ("Call", Script::Latin, LeftToRight),
+                (" ", Script::Neutral, LeftToRight),
+                ("שלום", Script::Hebrew, RightToLeft),
+                (" ", Script::Neutral, RightToLeft),
+                ("עולם", Script::Hebrew, RightToLeft),
+                (" ", Script::Neutral, LeftToRight),
+                ("at", Script::Latin, LeftToRight),
+                (" ", Script::Neutral, LeftToRight),
+                ("10", Script::Digit, LeftToRight),
+                (":", Script::Neutral, LeftToRight),
+                ("30", Script::Digit, LeftToRight),
+            ]
+        );
+    }
+
+    #[test]
+    fn script_runs_numbers_in_hebrew() {
+        use Direction::*;
+        assert_eq!(
+            labels("פרק 12 Привет"),
+            vec![
+                ("פרק", Script::Hebrew, RightToLeft),
+                (" ", Script::Neutral, RightToLeft),
+                ("12", Script::Digit, LeftToRight),
+                (" ", Script::Neutral, RightToLeft),
+                ("Привет", Script::Other, LeftToRight),
+            ]
+        );
+    }
+
+    #[test]
+    fn script_runs_marks_and_lines() {
+        use Direction::*;
+        assert_eq!(
+            labels("ab\u{0301}\nבְּ"),
+            vec![
+                ("ab\u{0301}", Script::Latin, LeftToRight),
+                ("\n", Script::Neutral, LeftToRight),
+                ("בְּ", Script::Hebrew, RightToLeft),
+            ]
+        );
+        assert!(hbr_script_runs("").is_empty());
+    }
+}
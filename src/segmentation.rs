@@ -0,0 +1,420 @@
+pub mod unicode_block_hebrew {
+    use crate::final_forms::to_normal_form;
+    use crate::segmentation::{
+        letters, prefix_chains, stem_length_weight, suffix_cue, Letter, SUFFIXES,
+    };
+    use crate::to_final_forms;
+    use std::fmt;
+    use std::ops::Range;
+
+    /// A candidate split of a Hebrew word into prefixes, stem and pronominal suffix, see [`segment_hbr_word`].
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct HebrewSegmentation {
+        /// The prefix letters in order, with an article hidden under the vowel of ב, כ or ל written as ה
+        pub prefixes: Vec<char>,
+        /// The stem without points, ending in a final letter where needed
+        pub stem: String,
+        /// The byte offsets of the stem in the given word
+        pub stem_span: Range<usize>,
+        /// The pronominal suffix without points, if any
+        pub suffix: Option<String>,
+        /// How plausible the segmentation is, higher is more plausible
+        pub score: f64,
+    }
+
+    impl fmt::Display for HebrewSegmentation {
+        /// Writes the segments joined by `+`, e.g. ו+ב+ה+בית.
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            for prefix in &self.prefixes {
+                write!(f, "{}+", prefix)?;
+            }
+            f.write_str(&self.stem)?;
+            if let Some(suffix) = &self.suffix {
+                write!(f, "+{}", suffix)?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Splits a Hebrew word into candidate segmentations of prefixes (ו, ה, ב, כ, ל, מ, ש), stem and
+    /// pronominal suffix, the most plausible first.
+    ///
+    /// Prefixes follow the order of the language: ו, then ש (also כש, מש, לכש), then one of ב, כ, ל, מ, then ה.
+    /// In pointed text, a prefix is only split off when its points fit, such as the shva of בְּ or
+    /// the patah and following dagesh of the article; a patah or qamats under ב, כ or ל before a dagesh
+    /// or guttural reveals the hidden article. A suffix is only split off with its vowel, such as the holam of בֵּיתוֹ.
+    /// In unpointed text every split is a candidate and the score weighs how often each letter starts a prefix,
+    /// and the length of the stem; three letter stems are the most plausible.
+    ///
+    /// A word with other characters than Hebrew letters and points is returned whole.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::segment_hbr_word;
+    ///
+    /// let pointed = segment_hbr_word("וּבַבַּיִת");
+    /// assert_eq!(pointed[0].to_string(), "ו+ב+ה+בית");
+    /// assert_eq!(pointed[0].stem_span, 8..20);
+    ///
+    /// let unpointed = segment_hbr_word("ובבית");
+    /// assert_eq!(unpointed[0].to_string(), "ו+ב+בית");
+    /// assert_eq!(segment_hbr_word("שלום")[0].to_string(), "שלום");
+    /// assert_eq!(segment_hbr_word("בֵּיתוֹ")[0].to_string(), "בית+ו");
+    /// ```
+    pub fn segment_hbr_word(word: &str) -> Vec<HebrewSegmentation> {
+        let letters: Vec<Letter> = match letters(word) {
+            Some(letters) if !letters.is_empty() => letters,
+            _ => {
+                return vec![HebrewSegmentation {
+                    prefixes: Vec::new(),
+                    stem: word.to_string(),
+                    stem_span: 0..word.len(),
+                    suffix: None,
+                    score: 1.0,
+                }]
+            }
+        };
+        let mut segmentations: Vec<HebrewSegmentation> = Vec::new();
+        for chain in prefix_chains(&letters) {
+            let rest = &letters[chain.consumed..];
+            let mut options: Vec<(Option<&str>, f64)> = vec![(None, 1.0)];
+            let mut confirmed_suffix = false;
+            for suffix in SUFFIXES {
+                let length = suffix.chars().count();
+                if rest.len() < length + 2 {
+                    continue;
+                }
+                let tail = &rest[rest.len() - length..];
+                if !tail
+                    .iter()
+                    .map(|l| l.normal)
+                    .eq(suffix.chars().map(to_normal_form))
+                {
+                    continue;
+                }
+                match suffix_cue(suffix, &rest[..rest.len() - length], tail) {
+                    Some(true) => {
+                        confirmed_suffix = true;
+                        options.push((Some(suffix), 1.0));
+                    }
+                    Some(false) => {}
+                    None => options.push((Some(suffix), 0.25)),
+                }
+            }
+            for (suffix, weight) in options {
+                let stem = &rest[..rest.len() - suffix.map_or(0, |s| s.chars().count())];
+                let mut score = chain.score * weight * stem_length_weight(stem.len());
+                if suffix.is_none() && confirmed_suffix {
+                    score *= 0.3;
+                }
+                let unpointed: String = stem.iter().map(|l| l.base).collect();
+                segmentations.push(HebrewSegmentation {
+                    prefixes: chain.prefixes.clone(),
+                    stem: to_final_forms(&unpointed).into_owned(),
+                    stem_span: stem[0].span.start..stem[stem.len() - 1].span.end,
+                    suffix: suffix.map(str::to_string),
+                    score,
+                });
+            }
+        }
+        segmentations.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(a.prefixes.len().cmp(&b.prefixes.len()))
+        });
+        segmentations
+    }
+}
+
+use crate::final_forms::to_normal_form;
+use crate::get_hbr_clusters;
+use hebrew_unicode_script::HebrewUnicodeScript;
+use std::ops::Range;
+
+const SHVA: char = '\u{05B0}';
+const HIRIQ: char = '\u{05B4}';
+const TSERE: char = '\u{05B5}';
+const SEGOL: char = '\u{05B6}';
+const PATAH: char = '\u{05B7}';
+const QAMATS: char = '\u{05B8}';
+
+/// The pronominal suffixes, longest first.
+pub(crate) const SUFFIXES: [&str; 19] = [
+    "יהם", "יהן", "יכם", "יכן", "ינו", "יו", "יה", "יך", "הם", "הן", "כם", "כן", "נו", "ו", "ה",
+    "ך", "י", "ם", "ן",
+];
+
+/// A letter of a word with the points that matter for segmentation.
+pub(crate) struct Letter {
+    /// The letter as written
+    pub(crate) base: char,
+    /// The letter with a final form replaced by the normal form
+    pub(crate) normal: char,
+    /// The first vowel or semi-vowel point
+    pub(crate) vowel: Option<char>,
+    pub(crate) dagesh: bool,
+    pub(crate) span: Range<usize>,
+}
+
+impl Letter {
+    fn has_vowel(&self, vowels: &[char]) -> bool {
+        self.vowel.map_or(false, |vowel| vowels.contains(&vowel))
+    }
+
+    fn is_guttural(&self) -> bool {
+        matches!(self.normal, 'א' | 'ה' | 'ח' | 'ע' | 'ר')
+    }
+
+    fn is_hataf(&self) -> bool {
+        matches!(self.vowel, Some('\u{05B1}'..='\u{05B3}'))
+    }
+}
+
+/// Returns the letters of a word, or `None` when the word has other characters than Hebrew letters and points.
+pub(crate) fn letters(word: &str) -> Option<Vec<Letter>> {
+    let mut letters = Vec::new();
+    for cluster in get_hbr_clusters(word) {
+        let base = cluster.base().filter(|c| c.is_hbr_consonant())?;
+        letters.push(Letter {
+            base,
+            normal: to_normal_form(base),
+            vowel: cluster
+                .marks()
+                .find(|&c| c.is_hbr_point_vowel() || c.is_hbr_point_semi_vowel()),
+            dagesh: cluster.has_mark('\u{05BC}'),
+            span: cluster.span.clone(),
+        });
+    }
+    Some(letters)
+}
+
+/// A way to split off the prefixes of a word.
+pub(crate) struct PrefixChain {
+    pub(crate) prefixes: Vec<char>,
+    /// The number of letters taken by the prefixes
+    pub(crate) consumed: usize,
+    pub(crate) score: f64,
+}
+
+/// The prefix slots in the order of the language; every slot is used at most once.
+const SLOTS: [&[&str]; 4] = [
+    &["ו"],
+    &["לכש", "כש", "מש", "ש"],
+    &["ב", "כ", "ל", "מ"],
+    &["ה"],
+];
+
+/// How often a word starting with the letter has it as a prefix, for unpointed text.
+fn prefix_weight(letter: char) -> f64 {
+    match letter {
+        'ו' => 0.7,
+        'ה' | 'ב' | 'ל' => 0.6,
+        'מ' | 'ש' => 0.35,
+        _ => 0.3,
+    }
+}
+
+/// The weight of a stem by its number of letters.
+pub(crate) fn stem_length_weight(length: usize) -> f64 {
+    match length {
+        0 | 1 => 0.0,
+        2 => 0.3,
+        3 => 1.0,
+        4 => 0.9,
+        5 => 0.6,
+        _ => 0.4,
+    }
+}
+
+/// The result of checking the points of a prefix letter.
+#[derive(Clone, Copy, PartialEq)]
+enum Cue {
+    /// The letter has no points
+    Unpointed,
+    /// The points fit a prefix
+    Prefix,
+    /// The points fit a prefix with a hidden article
+    PrefixWithArticle,
+    /// The points do not fit a prefix
+    NoPrefix,
+}
+
+/// Checks the points of a possible prefix letter against the letter that follows it.
+fn prefix_cue(letters: &[Letter], i: usize) -> Cue {
+    let letter = &letters[i];
+    let next = match letters.get(i + 1) {
+        Some(next) => next,
+        None => return Cue::NoPrefix,
+    };
+    if letter.vowel.is_none() && !letter.dagesh {
+        return Cue::Unpointed;
+    }
+    let doubled = next.dagesh || next.is_guttural();
+    let fits = match letter.normal {
+        'ו' => letter.vowel.is_some() || letter.dagesh,
+        'ב' | 'כ' | 'ל' => {
+            if letter.has_vowel(&[PATAH, QAMATS]) && doubled && !next.is_hataf() {
+                return Cue::PrefixWithArticle;
+            }
+            letter.has_vowel(&[SHVA])
+                || (letter.has_vowel(&[HIRIQ]) && next.has_vowel(&[SHVA]))
+                || (letter.vowel.is_some() && next.is_hataf())
+                || (letter.normal == 'ל' && letter.has_vowel(&[TSERE]) && next.normal == 'א')
+        }
+        'מ' => {
+            (letter.has_vowel(&[HIRIQ]) && next.dagesh)
+                || (letter.has_vowel(&[TSERE]) && next.is_guttural())
+        }
+        'ש' => letter.has_vowel(&[SEGOL, PATAH]) && doubled,
+        'ה' => {
+            (letter.has_vowel(&[PATAH, QAMATS]) && doubled)
+                || (letter.has_vowel(&[SEGOL]) && next.is_guttural())
+        }
+        _ => false,
+    };
+    if fits {
+        Cue::Prefix
+    } else {
+        Cue::NoPrefix
+    }
+}
+
+/// Returns every way to split off prefixes, leaving at least two letters.
+pub(crate) fn prefix_chains(letters: &[Letter]) -> Vec<PrefixChain> {
+    let mut chains = Vec::new();
+    extend_chains(
+        letters,
+        0,
+        PrefixChain {
+            prefixes: Vec::new(),
+            consumed: 0,
+            score: 1.0,
+        },
+        &mut chains,
+    );
+    chains
+}
+
+fn extend_chains(
+    letters: &[Letter],
+    slot: usize,
+    chain: PrefixChain,
+    chains: &mut Vec<PrefixChain>,
+) {
+    let first = chain.consumed;
+    let mut could_continue = false;
+    for (s, options) in SLOTS.iter().enumerate().skip(slot) {
+        for option in options.iter() {
+            let length = option.chars().count();
+            if letters.len() < first + length + 2
+                || !letters[first..first + length]
+                    .iter()
+                    .map(|l| l.normal)
+                    .eq(option.chars())
+            {
+                continue;
+            }
+            let mut next = PrefixChain {
+                prefixes: chain.prefixes.clone(),
+                consumed: first + length,
+                score: chain.score,
+            };
+            let mut article = false;
+            let mut fits = true;
+            for i in first..first + length {
+                match prefix_cue(letters, i) {
+                    Cue::Unpointed => next.score *= prefix_weight(letters[i].normal),
+                    Cue::Prefix => {}
+                    Cue::PrefixWithArticle => article = s == 2,
+                    Cue::NoPrefix => fits = false,
+                }
+                next.prefixes.push(letters[i].normal);
+            }
+            if !fits {
+                continue;
+            }
+            could_continue = true;
+            if article {
+                next.prefixes.push('ה');
+                extend_chains(letters, SLOTS.len(), next, chains);
+            } else {
+                extend_chains(letters, s + 1, next, chains);
+            }
+        }
+    }
+    // the weight of keeping the first letter of the stem, when it could have been a prefix
+    let keep = match prefix_cue(letters, first) {
+        _ if !could_continue => 1.0,
+        Cue::Unpointed => 1.0 - prefix_weight(letters[first].normal),
+        Cue::NoPrefix => 1.0,
+        Cue::Prefix | Cue::PrefixWithArticle => 0.3,
+    };
+    chains.push(PrefixChain {
+        score: chain.score * keep,
+        ..chain
+    });
+}
+
+/// Checks the points of a possible suffix: `Some(true)` when they fit,
+/// `Some(false)` when they do not and `None` for unpointed letters.
+pub(crate) fn suffix_cue(suffix: &str, stem: &[Letter], tail: &[Letter]) -> Option<bool> {
+    let last = &stem[stem.len() - 1];
+    let first = &tail[0];
+    if tail.iter().all(|l| l.vowel.is_none() && !l.dagesh) && last.vowel.is_none() {
+        return None;
+    }
+    let holam = |l: &Letter| l.has_vowel(&['\u{05B9}']);
+    let fits = match suffix {
+        "ו" => holam(first),
+        "ה" => first.dagesh,
+        "ך" => {
+            first.has_vowel(&[QAMATS]) || (first.has_vowel(&[SHVA]) && last.has_vowel(&[TSERE]))
+        }
+        "נו" => tail[1].dagesh,
+        "כם" | "כן" | "הם" | "הן" => first.has_vowel(&[SEGOL, TSERE]),
+        "י" => last.has_vowel(&[HIRIQ]) && first.vowel.is_none(),
+        "ם" | "ן" => last.has_vowel(&[QAMATS]),
+        _ => last.has_vowel(&[TSERE, QAMATS, SEGOL]) && first.vowel.is_none(),
+    };
+    Some(fits)
+}
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+
+    fn best(word: &str) -> String {
+        segment_hbr_word(word)[0].to_string()
+    }
+
+    #[test]
+    fn segment_pointed_words() {
+        assert_eq!(best("לַבַּיִת"), "ל+ה+בית");
+        assert_eq!(best("בְּבַיִת"), "ב+בית");
+        assert_eq!(best("הַבַּיִת"), "ה+בית");
+        assert_eq!(best("בַּיִת"), "בית");
+        assert_eq!(best("וּכְשֶׁהַמֶּלֶךְ"), "ו+כ+ש+ה+מלך");
+        assert_eq!(best("מִבֵּיתְךָ"), "מ+בית+ך");
+        assert!(segment_hbr_word("שָׁלוֹם")
+            .iter()
+            .all(|s| s.prefixes.is_empty()));
+    }
+
+    #[test]
+    fn segment_unpointed_words() {
+        assert_eq!(best("הבית"), "ה+בית");
+        assert_eq!(best("לבן"), "לבן");
+        assert_eq!(best("מלכה"), "מלכה");
+        let candidates: Vec<String> = segment_hbr_word("ובבית")
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert!(candidates.contains(&"ו+בבית".to_string()));
+        assert!(candidates.contains(&"ובבית".to_string()));
+        assert!(segment_hbr_word("ביתו")
+            .iter()
+            .any(|s| s.to_string() == "בית+ו"));
+        assert_eq!(best("abc"), "abc");
+    }
+}
@@ -199,6 +199,181 @@ pub mod unicode_block_hebrew {
             .collect()
     }
 }
+
+pub mod unicode_block_alphabetic_presentation_form {
+    use super::unicode_block_hebrew::*;
+    use crate::presentation_forms::with_decomposed_apf;
+    use std::borrow::Cow;
+
+    /// Shows all Hebrew accents that are found in the given string, after decomposing the Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::show_hbr_accent_apf;
+    ///
+    /// let input_str = "\u{FB2F}\u{0596}";
+    /// let input_str_showed = show_hbr_accent_apf(input_str);
+    /// assert_eq!(input_str_showed.as_ref(), "א\u{0596}");
+    /// ```
+    pub fn show_hbr_accent_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, show_hbr_accent)
+    }
+
+    /// Shows all Hebrew marks that are found in the given string, after decomposing the Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::show_hbr_mark_apf;
+    ///
+    /// let input_str = "\u{FB2F}\u{05AF}";
+    /// let input_str_showed = show_hbr_mark_apf(input_str);
+    /// assert_eq!(input_str_showed.as_ref(), "א\u{05AF}");
+    /// ```
+    pub fn show_hbr_mark_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, show_hbr_mark)
+    }
+
+    /// Shows all Hebrew points that are found in the given string, including the points of Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::show_hbr_point_apf;
+    ///
+    /// let input_str = "\u{FB2F}\u{0596}";
+    /// let input_str_showed = show_hbr_point_apf(input_str);
+    /// assert_eq!(input_str_showed.as_ref(), "\u{05D0}\u{05B8}");
+    /// ```
+    pub fn show_hbr_point_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, show_hbr_point)
+    }
+
+    /// Shows all Hebrew point vowels that are found in the given string, including those of Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::show_hbr_point_vowel_apf;
+    ///
+    /// let input_str = "\u{FB2F}\u{FB31}";
+    /// let input_str_showed = show_hbr_point_vowel_apf(input_str);
+    /// assert_eq!(input_str_showed.as_ref(), "\u{05D0}\u{05B8}ב");
+    /// ```
+    pub fn show_hbr_point_vowel_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, show_hbr_point_vowel)
+    }
+
+    /// Shows all Hebrew point semi-vowels that are found in the given string, after decomposing the Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::show_hbr_point_semi_vowel_apf;
+    ///
+    /// let input_str = "\u{FB31}\u{05B0}";
+    /// let input_str_showed = show_hbr_point_semi_vowel_apf(input_str);
+    /// assert_eq!(input_str_showed.as_ref(), "ב\u{05B0}");
+    /// ```
+    pub fn show_hbr_point_semi_vowel_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, show_hbr_point_semi_vowel)
+    }
+
+    /// Shows all Hebrew point reading signs that are found in the given string, including those of Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::show_hbr_point_reading_sign_apf;
+    ///
+    /// let input_str = "\u{FB2F}\u{FB31}";
+    /// let input_str_showed = show_hbr_point_reading_sign_apf(input_str);
+    /// assert_eq!(input_str_showed.as_ref(), "אבּ");
+    /// ```
+    pub fn show_hbr_point_reading_sign_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, show_hbr_point_reading_sign)
+    }
+
+    /// Shows all Hebrew punctuations that are found in the given string, after decomposing the Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::show_hbr_punctuation_apf;
+    ///
+    /// let input_str = "\u{FB31}\u{05BE}";
+    /// let input_str_showed = show_hbr_punctuation_apf(input_str);
+    /// assert_eq!(input_str_showed.as_ref(), "ב\u{05BE}");
+    /// ```
+    pub fn show_hbr_punctuation_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, show_hbr_punctuation)
+    }
+
+    /// Shows all Hebrew letters (final and normal) that are found in the given string, including Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::show_hbr_consonant_apf;
+    ///
+    /// let input_str = "A\u{FB31}B";
+    /// let input_str_showed = show_hbr_consonant_apf(input_str);
+    /// assert_eq!(input_str_showed.as_ref(), "AבB");
+    /// ```
+    pub fn show_hbr_consonant_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, show_hbr_consonant)
+    }
+
+    /// Shows all Hebrew normal letters that are found in the given string, including Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::show_hbr_consonant_normal_apf;
+    ///
+    /// let input_str = "A\u{FB31}B\u{FB43}";
+    /// let input_str_showed = show_hbr_consonant_normal_apf(input_str);
+    /// assert_eq!(input_str_showed.as_ref(), "AבB");
+    /// ```
+    pub fn show_hbr_consonant_normal_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, show_hbr_consonant_normal)
+    }
+
+    /// Shows all Hebrew final letters that are found in the given string, including Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::show_hbr_consonant_final_apf;
+    ///
+    /// let input_str = "A\u{FB31}B\u{FB43}";
+    /// let input_str_showed = show_hbr_consonant_final_apf(input_str);
+    /// assert_eq!(input_str_showed.as_ref(), "ABף");
+    /// ```
+    pub fn show_hbr_consonant_final_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, show_hbr_consonant_final)
+    }
+
+    /// Shows all Hebrew yod triangles that are found in the given string, after decomposing the Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::show_hbr_yod_triangle_apf;
+    ///
+    /// let input_str = format!("{}\u{FB31}", '\u{05EF}');
+    /// let input_str_showed = show_hbr_yod_triangle_apf(&input_str);
+    /// let expected_result = format!("{}ב", '\u{05EF}');
+    /// assert_eq!(input_str_showed.as_ref(), expected_result);
+    /// ```
+    pub fn show_hbr_yod_triangle_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, show_hbr_yod_triangle)
+    }
+
+    /// Shows all Yiddish ligatures that are found in the given string, including Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::show_hbr_ligature_yiddish_apf;
+    ///
+    /// let input_str = "\u{FB1F}\u{FB31}";
+    /// let input_str_showed = show_hbr_ligature_yiddish_apf(input_str);
+    /// assert_eq!(input_str_showed.as_ref(), "ײב");
+    /// ```
+    pub fn show_hbr_ligature_yiddish_apf(string: &str) -> Cow<'_, str> {
+        with_decomposed_apf(string, show_hbr_ligature_yiddish)
+    }
+}
 #[cfg(test)]
 mod unit_test {
     use crate::*;
@@ -293,4 +468,11 @@ mod unit_test {
         let input_str_showed = show_hbr_ligature_yiddish(input_str);
         assert_eq!(input_str_showed.as_ref(), "X נתחכמה װױײ Z");
     }
+
+    #[test]
+    pub fn test_showing_hbr_point_vowel_apf() {
+        let input_str = "\u{FB2C}\u{FB2F}\u{FB4B}";
+        let input_str_showed = show_hbr_point_vowel_apf(input_str);
+        assert_eq!(input_str_showed.as_ref(), "שאָוֹ");
+    }
 }
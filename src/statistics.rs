@@ -1,4 +1,6 @@
 pub mod unicode_block_hebrew {
+    use crate::clusters::is_hbr_combining;
+    use crate::segment_hbr_word;
     use hebrew_unicode_script::HebrewUnicodeScript;
     use std::collections::HashMap;
 
@@ -90,70 +92,135 @@ pub mod unicode_block_hebrew {
         }
         char_frequency
     }
+
+    /// Get the frequency of the stems of the Hebrew words in the given string
+    ///
+    /// Every word is split by [`crate::segment_hbr_word`] and its most plausible stem is counted,
+    /// without points and with final letters where needed.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::get_hbr_stem_frequency;
+    ///
+    /// let test_string = "וּבַבַּיִת, לַבַּיִת וְהַבַּיִת";
+    /// let struct_result = get_hbr_stem_frequency(test_string);
+    /// assert_eq!(struct_result.len(), 1);
+    /// assert_eq!(struct_result.get(&"בית".to_string()), Some(&3));
+    /// ```
+    pub fn get_hbr_stem_frequency(s: &str) -> HashMap<String, usize> {
+        let mut stem_frequency: HashMap<String, usize> = HashMap::new();
+
+        for word in s.split(|c: char| !(c.is_hbr_consonant() || is_hbr_combining(c))) {
+            if let Some(best) = segment_hbr_word(word).into_iter().next() {
+                if !best.stem.is_empty() && best.stem.chars().any(|c| c.is_hbr_consonant()) {
+                    *stem_frequency.entry(best.stem).or_insert(0) += 1;
+                }
+            }
+        }
+        stem_frequency
+    }
+}
+
+pub mod unicode_block_alphabetic_presentation_form {
+    use super::unicode_block_hebrew::*;
+    use crate::decompose_apf;
+    use std::collections::HashMap;
+
+    /// Get Hebrew character types for a given string, after decomposing the Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::get_hbr_character_types_apf;
+    ///
+    /// let test_string = "\u{FB31}";
+    /// let struct_result = get_hbr_character_types_apf(test_string);
+    /// assert!(struct_result.consonant);
+    /// assert!(struct_result.point_reading_sign);
+    /// assert!(!struct_result.non_hebrew);
+    /// ```
+    pub fn get_hbr_character_types_apf(text: &str) -> HebrewCharacterTypes {
+        get_hbr_character_types(&decompose_apf(text))
+    }
+
+    /// Get the frequency hebrew characters of the given string, after decomposing the Hebrew presentation forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::get_hbr_character_frequency_apf;
+    ///
+    /// let test_string = "\u{FB31}ב";
+    /// let struct_result = get_hbr_character_frequency_apf(test_string);
+    /// assert_eq!(struct_result.get("ב"), Some(&2));
+    /// assert_eq!(struct_result.get("\u{05BC}"), Some(&1));
+    /// ```
+    pub fn get_hbr_character_frequency_apf(s: &str) -> HashMap<String, usize> {
+        get_hbr_character_frequency(&decompose_apf(s))
+    }
 }
 
 #[cfg(test)]
 mod unit_test {
+    use super::unicode_block_alphabetic_presentation_form::*;
     use super::unicode_block_hebrew::*;
 
     #[test]
     fn hbr_character_types_no_hebrew_chars() {
         let test_string = "no hebrew characters";
         let struct_result = get_hbr_character_types(test_string);
-        assert_eq!(struct_result.accent, false);
-        assert_eq!(struct_result.consonant, false);
-        assert_eq!(struct_result.consonant_normal, false);
-        assert_eq!(struct_result.consonant_final, false);
-        assert_eq!(struct_result.ligature_yiddish, false);
-        assert_eq!(struct_result.mark, false);
-        assert_eq!(struct_result.point, false);
-        assert_eq!(struct_result.point_vowel, false);
-        assert_eq!(struct_result.point_semi_vowel, false);
-        assert_eq!(struct_result.point_reading_sign, false);
-        assert_eq!(struct_result.punctuation, false);
-        assert_eq!(struct_result.yod_triangle, false);
-        assert_eq!(struct_result.whitespace, true);
-        assert_eq!(struct_result.non_hebrew, true);
+        assert!(!struct_result.accent);
+        assert!(!struct_result.consonant);
+        assert!(!struct_result.consonant_normal);
+        assert!(!struct_result.consonant_final);
+        assert!(!struct_result.ligature_yiddish);
+        assert!(!struct_result.mark);
+        assert!(!struct_result.point);
+        assert!(!struct_result.point_vowel);
+        assert!(!struct_result.point_semi_vowel);
+        assert!(!struct_result.point_reading_sign);
+        assert!(!struct_result.punctuation);
+        assert!(!struct_result.yod_triangle);
+        assert!(struct_result.whitespace);
+        assert!(struct_result.non_hebrew);
     }
 
     #[test]
     fn get_hbr_character_types_empty_string() {
         let test_string = "";
         let struct_result: HebrewCharacterTypes = get_hbr_character_types(test_string);
-        assert_eq!(struct_result.accent, false);
-        assert_eq!(struct_result.consonant, false);
-        assert_eq!(struct_result.consonant_normal, false);
-        assert_eq!(struct_result.consonant_final, false);
-        assert_eq!(struct_result.ligature_yiddish, false);
-        assert_eq!(struct_result.mark, false);
-        assert_eq!(struct_result.point, false);
-        assert_eq!(struct_result.point_vowel, false);
-        assert_eq!(struct_result.point_semi_vowel, false);
-        assert_eq!(struct_result.point_reading_sign, false);
-        assert_eq!(struct_result.punctuation, false);
-        assert_eq!(struct_result.yod_triangle, false);
-        assert_eq!(struct_result.whitespace, false);
-        assert_eq!(struct_result.non_hebrew, false);
+        assert!(!struct_result.accent);
+        assert!(!struct_result.consonant);
+        assert!(!struct_result.consonant_normal);
+        assert!(!struct_result.consonant_final);
+        assert!(!struct_result.ligature_yiddish);
+        assert!(!struct_result.mark);
+        assert!(!struct_result.point);
+        assert!(!struct_result.point_vowel);
+        assert!(!struct_result.point_semi_vowel);
+        assert!(!struct_result.point_reading_sign);
+        assert!(!struct_result.punctuation);
+        assert!(!struct_result.yod_triangle);
+        assert!(!struct_result.whitespace);
+        assert!(!struct_result.non_hebrew);
     }
     #[test]
     fn hbr_character_types_hebrew_text() {
         let input_string = "בְּרֵאשִׁית בָּרָא אֱלֹהִים אֵת הַשָּׁמַיִם וְאֵת הָאָרֶץ׃";
         let struct_result = get_hbr_character_types(input_string);
         println!("test_hbr_character_types_no_3:\n{:?}", struct_result);
-        assert_eq!(struct_result.accent, false);
-        assert_eq!(struct_result.consonant, true);
-        assert_eq!(struct_result.consonant_normal, true);
-        assert_eq!(struct_result.consonant_final, true);
-        assert_eq!(struct_result.ligature_yiddish, false);
-        assert_eq!(struct_result.mark, false);
-        assert_eq!(struct_result.point, true);
-        assert_eq!(struct_result.point_vowel, true);
-        assert_eq!(struct_result.point_semi_vowel, true);
-        assert_eq!(struct_result.point_reading_sign, true);
-        assert_eq!(struct_result.punctuation, true);
-        assert_eq!(struct_result.yod_triangle, false);
-        assert_eq!(struct_result.whitespace, true);
-        assert_eq!(struct_result.non_hebrew, false);
+        assert!(!struct_result.accent);
+        assert!(struct_result.consonant);
+        assert!(struct_result.consonant_normal);
+        assert!(struct_result.consonant_final);
+        assert!(!struct_result.ligature_yiddish);
+        assert!(!struct_result.mark);
+        assert!(struct_result.point);
+        assert!(struct_result.point_vowel);
+        assert!(struct_result.point_semi_vowel);
+        assert!(struct_result.point_reading_sign);
+        assert!(struct_result.punctuation);
+        assert!(!struct_result.yod_triangle);
+        assert!(struct_result.whitespace);
+        assert!(!struct_result.non_hebrew);
     }
 
     #[test]
@@ -163,12 +230,31 @@ mod unit_test {
         assert!(freq_map.is_empty());
     }
 
+    #[test]
+    fn get_hbr_stem_frequency_unpointed_and_mixed() {
+        let test_string = "הבית ובבית בית, the house";
+        let freq_map = get_hbr_stem_frequency(test_string);
+        assert_eq!(freq_map.len(), 1);
+        assert_eq!(freq_map.get("בית"), Some(&3));
+        assert!(get_hbr_stem_frequency("no hebrew characters").is_empty());
+    }
+
     #[test]
     fn get_hbr_character_frequency_hebrew_and_ascii() {
         let test_string = "Xבהב";
         let freq_map = get_hbr_character_frequency(test_string);
-        assert_eq!(freq_map.contains_key("X"), false);
-        assert_eq!(freq_map.get(&"ב".to_string()), Some(&2));
-        assert_eq!(freq_map.get(&"ה".to_string()), Some(&1));
+        assert!(!freq_map.contains_key("X"));
+        assert_eq!(freq_map.get("ב"), Some(&2));
+        assert_eq!(freq_map.get("ה"), Some(&1));
+    }
+
+    #[test]
+    fn hbr_character_types_presentation_forms() {
+        let test_string = "\u{FB2C}\u{FB4F}";
+        assert!(get_hbr_character_types(test_string).non_hebrew);
+        let struct_result = get_hbr_character_types_apf(test_string);
+        assert!(struct_result.consonant_normal);
+        assert!(struct_result.point_reading_sign);
+        assert!(!struct_result.non_hebrew);
     }
 }
@@ -0,0 +1,117 @@
+pub mod unicode_block_hebrew {
+    use crate::ordering::{reorder_line, wrap_lines};
+    use crate::{remove_hbr_accent, remove_hbr_mark, remove_hbr_point};
+    use std::borrow::Cow;
+
+    /// How a terminal handles bidirectional text.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum TerminalBidi {
+        /// The terminal reorders right-to-left text itself, lines are printed in logical order
+        Aware,
+        /// The terminal prints characters from left to right, lines are printed in visual order
+        Unaware,
+    }
+
+    /// Which combining characters a terminal can show on top of their consonant.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum TerminalCombining {
+        /// Points, accents and marks are kept
+        All,
+        /// Only points are kept, accents and marks are removed
+        Points,
+        /// Points, accents and marks are removed
+        None,
+    }
+
+    /// The options for [`render_hbr_for_terminal`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct TerminalOptions {
+        pub bidi: TerminalBidi,
+        pub combining: TerminalCombining,
+    }
+
+    impl Default for TerminalOptions {
+        /// A terminal without bidirectional support that combines all characters.
+        fn default() -> Self {
+            TerminalOptions {
+                bidi: TerminalBidi::Unaware,
+                combining: TerminalCombining::All,
+            }
+        }
+    }
+
+    /// Prepares text for printing to a terminal.
+    ///
+    /// The text is wrapped at whitespace into lines of at most `width` columns first (0 disables wrapping),
+    /// so that every line reads correctly on its own. For terminals without bidirectional support
+    /// each line is then put in visual order; points, accents and marks stay with their consonant.
+    /// Combining characters the terminal cannot show are removed.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{render_hbr_for_terminal, TerminalBidi, TerminalCombining, TerminalOptions};
+    ///
+    /// let text = "בְּרֵאשִׁ֖ית בָּרָ֣א אֱלֹהִ֑ים";
+    /// let options = TerminalOptions { bidi: TerminalBidi::Unaware, combining: TerminalCombining::None };
+    /// assert_eq!(render_hbr_for_terminal(text, 12, &options), "ארב תישארב\nםיהלא");
+    ///
+    /// let options = TerminalOptions { bidi: TerminalBidi::Aware, combining: TerminalCombining::Points };
+    /// assert_eq!(render_hbr_for_terminal(text, 12, &options), "בְּרֵאשִׁית בָּרָא\nאֱלֹהִים");
+    /// ```
+    pub fn render_hbr_for_terminal(text: &str, width: usize, options: &TerminalOptions) -> String {
+        let text = match options.combining {
+            TerminalCombining::All => Cow::Borrowed(text),
+            TerminalCombining::Points => {
+                Cow::Owned(remove_hbr_mark(&remove_hbr_accent(text)).into_owned())
+            }
+            TerminalCombining::None => Cow::Owned(
+                remove_hbr_point(&remove_hbr_mark(&remove_hbr_accent(text))).into_owned(),
+            ),
+        };
+        let lines = wrap_lines(&text, width);
+        match options.bidi {
+            TerminalBidi::Aware => lines.join("\n"),
+            TerminalBidi::Unaware => lines
+                .iter()
+                .map(|line| reorder_line(line))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+
+    #[test]
+    fn terminal_unaware_keeps_points_with_consonants() {
+        let rendered = render_hbr_for_terminal("שָׁלוֹם", 0, &TerminalOptions::default());
+        assert_eq!(rendered, "םוֹלשָׁ");
+    }
+
+    #[test]
+    fn terminal_mixed_text_and_wrapping() {
+        let options = TerminalOptions::default();
+        assert_eq!(
+            render_hbr_for_terminal("הקובץ data.txt נשמר", 0, &options),
+            "רמשנ data.txt ץבוקה"
+        );
+        assert_eq!(
+            render_hbr_for_terminal("אחת שתיים שלוש", 9, &options),
+            "םייתש תחא\nשולש"
+        );
+    }
+
+    #[test]
+    fn terminal_aware_only_wraps() {
+        let options = TerminalOptions {
+            bidi: TerminalBidi::Aware,
+            combining: TerminalCombining::All,
+        };
+        assert_eq!(
+            render_hbr_for_terminal("אחת שתיים\nשלוש", 0, &options),
+            "אחת שתיים\nשלוש"
+        );
+    }
+}
@@ -0,0 +1,234 @@
+pub mod unicode_block_hebrew {
+    use crate::{
+        get_hbr_clusters, get_hbr_vowels, DageshKind, HebrewClusterVowel, ShvaKind, VowelKind,
+    };
+    use hebrew_unicode_script::HebrewUnicodeScript;
+
+    /// The pronunciation tradition used for a transcription.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Pronunciation {
+        /// The reconstructed pronunciation of the Tiberian Masoretes
+        Tiberian,
+        /// Contemporary Israeli Hebrew
+        ModernIsraeli,
+        /// The traditional Ashkenazi reading
+        Ashkenazi,
+    }
+
+    /// Transcribes pointed Hebrew into the International Phonetic Alphabet.
+    ///
+    /// Shva, dagesh, begadkefat letters and furtive patah are resolved with [`crate::get_hbr_vowels`].
+    /// Characters outside of Hebrew words are copied unchanged; a maqaf becomes a space.
+    /// Stress and syllable boundaries are not marked.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{to_ipa, Pronunciation};
+    ///
+    /// assert_eq!(to_ipa("שָׁלוֹם", Pronunciation::ModernIsraeli), "ʃalom");
+    /// assert_eq!(to_ipa("רוּחַ", Pronunciation::Tiberian), "ruaħ");
+    /// assert_eq!(to_ipa("בַּיִת", Pronunciation::Ashkenazi), "bajis");
+    /// ```
+    pub fn to_ipa(text: &str, pronunciation: Pronunciation) -> String {
+        let vowels = get_hbr_vowels(text);
+        let mut next_vowel = 0;
+        let mut ipa = String::with_capacity(text.len());
+        for cluster in get_hbr_clusters(text) {
+            match vowels.get(next_vowel) {
+                Some(v) if v.cluster.span == cluster.span => {
+                    let previous = next_vowel.checked_sub(1).map(|p| &vowels[p]);
+                    let previous = previous.filter(|_| !v.word_start);
+                    let geresh = text[cluster.span.end..].starts_with('\u{05F3}');
+                    transcribe_cluster(&mut ipa, v, previous, geresh, pronunciation);
+                    next_vowel += 1;
+                }
+                _ => match cluster.base() {
+                    Some('\u{05BE}') => ipa.push(' '),
+                    Some('\u{05C0}') => ipa.push('|'),
+                    Some('\u{05C3}') => ipa.push('.'),
+                    Some(c) if c.is_hbr_block() => {}
+                    Some(_) => ipa.push_str(cluster.text),
+                    None => {}
+                },
+            }
+        }
+        ipa
+    }
+
+    fn transcribe_cluster(
+        ipa: &mut String,
+        current: &HebrewClusterVowel<'_>,
+        previous: Option<&HebrewClusterVowel<'_>>,
+        geresh: bool,
+        pronunciation: Pronunciation,
+    ) {
+        if current.is_mater_lectionis(previous) {
+            return;
+        }
+        let kind = current.vowel.map(|v| v.kind);
+        let vowel = vowel_ipa(current, previous, pronunciation);
+        if kind == Some(VowelKind::FurtivePatah) {
+            ipa.push_str(vowel);
+        }
+        if !matches!(kind, Some(VowelKind::HolamMale) | Some(VowelKind::Shureq))
+            || current.cluster.base() != Some('ו')
+        {
+            let consonant = consonant_ipa(current, geresh, pronunciation);
+            ipa.push_str(consonant);
+            if current.dagesh == Some(DageshKind::Forte) && pronunciation == Pronunciation::Tiberian
+            {
+                ipa.push_str(consonant);
+            }
+        }
+        if kind != Some(VowelKind::FurtivePatah) {
+            ipa.push_str(vowel);
+        }
+    }
+
+    fn vowel_ipa(
+        current: &HebrewClusterVowel<'_>,
+        previous: Option<&HebrewClusterVowel<'_>>,
+        pronunciation: Pronunciation,
+    ) -> &'static str {
+        use Pronunciation::*;
+        let point = match current.vowel {
+            Some(vowel) if vowel.kind == VowelKind::Shureq => '\u{05BB}',
+            Some(vowel) => vowel.point,
+            None if current.shva == Some(ShvaKind::Vocal) => {
+                return match pronunciation {
+                    Tiberian => "ă",
+                    Ashkenazi => "ə",
+                    ModernIsraeli if modern_pronounces_shva(current, previous) => "e",
+                    ModernIsraeli => "",
+                };
+            }
+            None => return "",
+        };
+        match (point, pronunciation) {
+            ('\u{05B1}', Tiberian) => "ɛ̆",
+            ('\u{05B2}', Tiberian) => "ă",
+            ('\u{05B3}', Tiberian) => "ɔ̆",
+            ('\u{05B1}', Ashkenazi) | ('\u{05B6}', Ashkenazi) | ('\u{05B6}', Tiberian) => "ɛ",
+            ('\u{05B3}', Ashkenazi) | ('\u{05B8}', Ashkenazi) | ('\u{05C7}', Ashkenazi) => "ɔ",
+            ('\u{05B8}', Tiberian) | ('\u{05C7}', Tiberian) => "ɔ",
+            ('\u{05B5}', Ashkenazi) => "ej",
+            ('\u{05B9}', Ashkenazi) | ('\u{05BA}', Ashkenazi) => "ɔj",
+            ('\u{05B1}', _) | ('\u{05B5}', _) | ('\u{05B6}', _) => "e",
+            ('\u{05B2}', _) | ('\u{05B7}', _) | ('\u{05B8}', _) => "a",
+            ('\u{05B4}', _) => "i",
+            ('\u{05BB}', _) => "u",
+            _ => "o",
+        }
+    }
+
+    /// Modern Israeli Hebrew drops most vocal shvas; it keeps them where a cluster is hard to pronounce.
+    fn modern_pronounces_shva(
+        current: &HebrewClusterVowel<'_>,
+        previous: Option<&HebrewClusterVowel<'_>>,
+    ) -> bool {
+        match previous {
+            Some(previous) => previous.shva.is_some(),
+            None => matches!(
+                current.cluster.base(),
+                Some('י' | 'ל' | 'מ' | 'נ' | 'ר' | 'ו' | 'ב' | 'כ' | 'ש')
+            ),
+        }
+    }
+
+    fn consonant_ipa(
+        current: &HebrewClusterVowel<'_>,
+        geresh: bool,
+        pronunciation: Pronunciation,
+    ) -> &'static str {
+        use Pronunciation::*;
+        let plosive = current.dagesh.is_some() && !current.cluster.has_mark('\u{05BF}');
+        let base = current.cluster.base().unwrap_or_default();
+        let sin = current.cluster.has_mark('\u{05C2}');
+        if geresh && pronunciation == ModernIsraeli {
+            match base {
+                'ג' => return "dʒ",
+                'ז' => return "ʒ",
+                'צ' | 'ץ' => return "tʃ",
+                _ => {}
+            }
+        }
+        match (base, pronunciation) {
+            ('א', Tiberian) => "ʔ",
+            ('א', _) => "",
+            ('ב', _) if plosive => "b",
+            ('ב', _) => "v",
+            ('ג', Tiberian) if !plosive => "ɣ",
+            ('ג', _) => "g",
+            ('ד', Tiberian) if !plosive => "ð",
+            ('ד', _) => "d",
+            ('ה', _) => "h",
+            ('ו', Tiberian) => "w",
+            ('ו', _) => "v",
+            ('ז', _) => "z",
+            ('ח', Tiberian) => "ħ",
+            ('ח', ModernIsraeli) => "χ",
+            ('ח', Ashkenazi) => "x",
+            ('ט', Tiberian) => "tˤ",
+            ('ט', _) => "t",
+            ('י', _) => "j",
+            ('כ', _) | ('ך', _) if plosive => "k",
+            ('כ', Ashkenazi) | ('ך', Ashkenazi) => "x",
+            ('כ', _) | ('ך', _) => "χ",
+            ('ל', _) => "l",
+            ('מ', _) | ('ם', _) => "m",
+            ('נ', _) | ('ן', _) => "n",
+            ('ס', _) => "s",
+            ('ע', Tiberian) => "ʕ",
+            ('ע', _) => "",
+            ('פ', _) | ('ף', _) if plosive => "p",
+            ('פ', _) | ('ף', _) => "f",
+            ('צ', Tiberian) | ('ץ', Tiberian) => "sˤ",
+            ('צ', _) | ('ץ', _) => "ts",
+            ('ק', Tiberian) => "q",
+            ('ק', _) => "k",
+            ('ר', ModernIsraeli) => "ʁ",
+            ('ר', _) => "r",
+            ('ש', _) if sin => "s",
+            ('ש', _) => "ʃ",
+            ('ת', _) if plosive => "t",
+            ('ת', Tiberian) => "θ",
+            ('ת', Ashkenazi) => "s",
+            _ => "t",
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+
+    #[test]
+    fn ipa_modern_israeli() {
+        let ipa = to_ipa("בְּרֵאשִׁית בָּרָא אֱלֹהִים", Pronunciation::ModernIsraeli);
+        assert_eq!(ipa, "beʁeʃit baʁa elohim");
+        assert_eq!(to_ipa("מִכְתָּב", Pronunciation::ModernIsraeli), "miχtav");
+        assert_eq!(to_ipa("כָּתְבוּ", Pronunciation::ModernIsraeli), "katvu");
+    }
+
+    #[test]
+    fn ipa_tiberian_gemination_and_spirants() {
+        assert_eq!(to_ipa("שַׁבָּת", Pronunciation::Tiberian), "ʃabbɔθ");
+        assert_eq!(to_ipa("כָּתְבוּ", Pronunciation::Tiberian), "kɔθăvu");
+        assert_eq!(to_ipa("גָּבֹהַּ", Pronunciation::Tiberian), "gɔvoah");
+    }
+
+    #[test]
+    fn ipa_ashkenazi() {
+        assert_eq!(to_ipa("תּוֹרָה", Pronunciation::Ashkenazi), "tɔjrɔ");
+        assert_eq!(to_ipa("בֵּית", Pronunciation::Ashkenazi), "bejs");
+    }
+
+    #[test]
+    fn ipa_maqaf_and_foreign_text() {
+        assert_eq!(
+            to_ipa("עַל־פְּנֵי abc", Pronunciation::ModernIsraeli),
+            "al pne abc"
+        );
+        assert_eq!(to_ipa("גִּ׳ינְס", Pronunciation::ModernIsraeli), "dʒins");
+    }
+}
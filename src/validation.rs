@@ -0,0 +1,290 @@
+pub mod unicode_block_hebrew {
+    use crate::presentation_forms::decompose_apf_char;
+    use crate::validation::combining_class;
+    use crate::{check_bidi_control, get_hbr_clusters, BidiControlIssueKind};
+    use hebrew_unicode_script::HebrewUnicodeScript;
+    use std::ops::Range;
+
+    /// How serious a problem found by [`validate_hbr`] is.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub enum Severity {
+        /// The text is probably shown as intended, but is not well-formed
+        Warning,
+        /// The text is broken
+        Error,
+    }
+
+    /// The kind of problem found by [`validate_hbr`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum DiagnosticKind {
+        /// Points, accents or marks without a Hebrew letter to carry them, e.g. after a space
+        OrphanMark,
+        /// More than one vowel or semi-vowel on one letter
+        MultipleVowels,
+        /// The same point, accent or mark twice on one letter
+        DuplicateMark,
+        /// A shin or sin dot on another letter than shin, or both dots on one shin
+        MisplacedShinDot,
+        /// A vowel on a final letter, except for shva and qamats on final kaf
+        VowelOnFinal,
+        /// A presentation form next to decomposed pointed letters, or with extra points of its own
+        MixedPresentationForms,
+        /// A problem with the bidirectional control characters, see [`check_bidi_control`]
+        BidiControl(BidiControlIssueKind),
+        /// Points, accents and marks of one letter that are not in canonical order
+        NonCanonicalOrder,
+    }
+
+    /// A problem found by [`validate_hbr`].
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Diagnostic {
+        /// The byte offsets of the offending characters
+        pub span: Range<usize>,
+        pub severity: Severity,
+        pub kind: DiagnosticKind,
+    }
+
+    /// Checks the given string for malformed Hebrew text and returns the problems found, sorted by span.
+    ///
+    /// Errors are reported for orphan marks, multiple vowels, duplicate marks, misplaced shin and sin dots,
+    /// vowels on final letters and unterminated or unmatched bidirectional control characters.
+    /// Warnings are reported for mixed presentation forms, directional overrides and non-canonical mark order.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{validate_hbr, DiagnosticKind, Severity};
+    ///
+    /// assert!(validate_hbr("בְּרֵאשִׁית").is_empty());
+    ///
+    /// let diagnostics = validate_hbr("שָׁלוֹם ָעוֹלָם");
+    /// assert_eq!(diagnostics.len(), 1);
+    /// assert_eq!(diagnostics[0].span, 15..17);
+    /// assert_eq!(diagnostics[0].kind, DiagnosticKind::OrphanMark);
+    /// assert_eq!(diagnostics[0].severity, Severity::Error);
+    /// ```
+    pub fn validate_hbr(text: &str) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        let mut push = |span: Range<usize>, severity: Severity, kind: DiagnosticKind| {
+            diagnostics.push(Diagnostic {
+                span,
+                severity,
+                kind,
+            })
+        };
+        let mut presentation_forms: Vec<Range<usize>> = Vec::new();
+        let mut decomposed_pointed = false;
+
+        for cluster in get_hbr_clusters(text) {
+            let start = cluster.span.start;
+            let decomposed = cluster.base().and_then(decompose_apf_char);
+            let letter = match (cluster.base(), decomposed) {
+                (_, Some(decomposed)) => decomposed.chars().next(),
+                (base, None) => base,
+            }
+            .filter(|&c| c.is_hbr_consonant() || c.is_hbr_ligature_yiddish());
+
+            // the marks of the cluster with their spans, starting with those of a presentation form
+            let base_len = cluster.base().map_or(0, char::len_utf8);
+            let mut marks: Vec<(char, Range<usize>)> = decomposed
+                .map(|d| {
+                    d.chars()
+                        .skip(1)
+                        .map(|c| (c, start..start + base_len))
+                        .collect()
+                })
+                .unwrap_or_default();
+            let own_marks = marks.len();
+            marks.extend(cluster.text[base_len..].char_indices().map(|(pos, c)| {
+                (
+                    c,
+                    start + base_len + pos..start + base_len + pos + c.len_utf8(),
+                )
+            }));
+            let marks_span = start + base_len..cluster.span.end;
+
+            let letter = match letter {
+                Some(letter) => letter,
+                None => {
+                    if !marks_span.is_empty() {
+                        push(marks_span, Severity::Error, DiagnosticKind::OrphanMark);
+                    }
+                    continue;
+                }
+            };
+            if decomposed.is_some() {
+                if marks.len() > own_marks {
+                    push(
+                        cluster.span.clone(),
+                        Severity::Warning,
+                        DiagnosticKind::MixedPresentationForms,
+                    );
+                } else {
+                    presentation_forms.push(cluster.span.clone());
+                }
+            } else if marks.iter().any(|(c, _)| c.is_hbr_point()) {
+                decomposed_pointed = true;
+            }
+
+            let mut vowels = 0;
+            let mut shin_dots = 0;
+            for (i, (mark, span)) in marks.iter().enumerate() {
+                if marks[..i].iter().any(|(other, _)| other == mark) {
+                    push(span.clone(), Severity::Error, DiagnosticKind::DuplicateMark);
+                    continue;
+                }
+                if mark.is_hbr_point_vowel() || mark.is_hbr_point_semi_vowel() {
+                    vowels += 1;
+                    if vowels > 1 {
+                        push(
+                            span.clone(),
+                            Severity::Error,
+                            DiagnosticKind::MultipleVowels,
+                        );
+                    } else if letter.is_hbr_consonant_final()
+                        && !(letter == 'ך' && matches!(mark, '\u{05B0}' | '\u{05B8}'))
+                    {
+                        push(span.clone(), Severity::Error, DiagnosticKind::VowelOnFinal);
+                    }
+                }
+                if matches!(mark, '\u{05C1}' | '\u{05C2}') {
+                    shin_dots += 1;
+                    if letter != 'ש' || shin_dots > 1 {
+                        push(
+                            span.clone(),
+                            Severity::Error,
+                            DiagnosticKind::MisplacedShinDot,
+                        );
+                    }
+                }
+            }
+
+            let classes: Vec<u8> = cluster.marks().map(combining_class).collect();
+            if classes
+                .windows(2)
+                .any(|pair| pair[1] != 0 && pair[0] > pair[1])
+            {
+                push(
+                    marks_span,
+                    Severity::Warning,
+                    DiagnosticKind::NonCanonicalOrder,
+                );
+            }
+        }
+
+        if decomposed_pointed {
+            for span in presentation_forms {
+                push(
+                    span,
+                    Severity::Warning,
+                    DiagnosticKind::MixedPresentationForms,
+                );
+            }
+        }
+        for issue in check_bidi_control(text) {
+            let severity = match issue.kind {
+                BidiControlIssueKind::Override => Severity::Warning,
+                _ => Severity::Error,
+            };
+            push(
+                issue.span,
+                severity,
+                DiagnosticKind::BidiControl(issue.kind),
+            );
+        }
+        diagnostics.sort_by_key(|diagnostic| (diagnostic.span.start, diagnostic.span.end));
+        diagnostics
+    }
+}
+
+/// Returns the Unicode canonical combining class of a Hebrew point, accent or mark, and 0 for other characters.
+pub(crate) fn combining_class(c: char) -> u8 {
+    match c {
+        '\u{05B0}'..='\u{05B9}' => (u32::from(c) - 0x05B0 + 10) as u8,
+        '\u{05BA}' => 19,
+        '\u{05BB}' => 20,
+        '\u{05BC}' => 21,
+        '\u{05BD}' => 22,
+        '\u{05BF}' => 23,
+        '\u{05C1}' => 24,
+        '\u{05C2}' => 25,
+        '\u{05C7}' => 18,
+        '\u{FB1E}' => 26,
+        '\u{0591}'
+        | '\u{0596}'
+        | '\u{059B}'
+        | '\u{05A2}'..='\u{05A7}'
+        | '\u{05AA}'
+        | '\u{05C5}' => 220,
+        '\u{059A}' | '\u{05AD}' => 222,
+        '\u{05AE}' => 228,
+        '\u{0592}'..='\u{05AF}' | '\u{05C4}' => 230,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+
+    fn kinds(text: &str) -> Vec<DiagnosticKind> {
+        validate_hbr(text).iter().map(|d| d.kind).collect()
+    }
+
+    #[test]
+    fn validate_points_on_letters() {
+        assert_eq!(kinds("בַָּ"), vec![DiagnosticKind::MultipleVowels]);
+        assert_eq!(kinds("בּּ"), vec![DiagnosticKind::DuplicateMark]);
+        assert_eq!(kinds("סׁ"), vec![DiagnosticKind::MisplacedShinDot]);
+        assert_eq!(kinds("שׁׂ"), vec![DiagnosticKind::MisplacedShinDot]);
+        assert_eq!(kinds("מֶלֶךְ לָךָ"), vec![]);
+        assert_eq!(kinds("שָׁלוֹםַ"), vec![DiagnosticKind::VowelOnFinal]);
+        assert_eq!(
+            kinds("a\u{05B8} \u{05BC}"),
+            vec![DiagnosticKind::OrphanMark; 2]
+        );
+    }
+
+    #[test]
+    fn validate_presentation_forms() {
+        assert!(validate_hbr("\u{FB31}\u{FB4B}").is_empty());
+        let diagnostics = validate_hbr("\u{FB31}ַ");
+        assert_eq!(diagnostics[0].kind, DiagnosticKind::MixedPresentationForms);
+        assert_eq!(diagnostics[0].span, 0..5);
+        assert_eq!(
+            kinds("\u{FB31}ית בַּיִת"),
+            vec![DiagnosticKind::MixedPresentationForms]
+        );
+        assert_eq!(
+            kinds("\u{FB31}ּ"),
+            vec![
+                DiagnosticKind::MixedPresentationForms,
+                DiagnosticKind::DuplicateMark
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_order_and_bidi() {
+        let diagnostics = validate_hbr("בּ\u{05B0}");
+        assert_eq!(diagnostics[0].kind, DiagnosticKind::NonCanonicalOrder);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!(diagnostics[0].span, 2..6);
+        assert_eq!(
+            kinds("\u{202E}abc\u{202C} \u{2067}שלום"),
+            vec![
+                DiagnosticKind::BidiControl(BidiControlIssueKind::Override),
+                DiagnosticKind::BidiControl(BidiControlIssueKind::Unterminated)
+            ]
+        );
+        assert_eq!(
+            combining_class_of("\u{05B0}\u{05BC}\u{0591}"),
+            vec![10, 21, 220]
+        );
+    }
+
+    fn combining_class_of(text: &str) -> Vec<u8> {
+        text.chars()
+            .map(crate::validation::combining_class)
+            .collect()
+    }
+}
@@ -0,0 +1,423 @@
+pub mod unicode_block_hebrew {
+    use crate::{get_hbr_clusters, HebrewCluster};
+    use hebrew_unicode_script::HebrewUnicodeScript;
+
+    /// The quality of a Hebrew vowel.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum VowelQuality {
+        A,
+        E,
+        I,
+        O,
+        U,
+    }
+
+    /// The (traditional) length of a Hebrew vowel.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum VowelLength {
+        Long,
+        Short,
+        /// The hateph vowels (compound shvas)
+        Reduced,
+    }
+
+    /// The way a Hebrew vowel is written and pronounced.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum VowelKind {
+        /// A vowel point without any special behaviour
+        Simple,
+        /// A hateph segol, hateph patah or hateph qamats
+        CompoundShva,
+        /// A patah under a word-final ḥet, ʿayin or mappiq-he, pronounced before the consonant
+        FurtivePatah,
+        /// A holam written with a vav as vowel letter
+        HolamMale,
+        /// A holam without vowel letter, including a holam on a consonantal vav
+        HolamHaser,
+        /// A hiriq followed by a yod as vowel letter
+        HiriqMale,
+        /// A vav with dagesh used as the vowel 'u'
+        Shureq,
+    }
+
+    /// A vowel found on a Hebrew consonant.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct HebrewVowel {
+        pub point: char,
+        pub quality: VowelQuality,
+        pub length: VowelLength,
+        pub kind: VowelKind,
+    }
+
+    /// The pronunciation of a simple shva.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum ShvaKind {
+        /// Shva na, pronounced as a short vowel
+        Vocal,
+        /// Shva nah, closing a syllable
+        Silent,
+    }
+
+    /// The function of a dagesh (or mappiq) point.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum DageshKind {
+        /// Dagesh lene, turning a begadkefat letter into a plosive
+        Lene,
+        /// Dagesh forte, doubling the consonant
+        Forte,
+        /// Mappiq, marking a word-final he as consonant
+        Mappiq,
+    }
+
+    /// A Hebrew consonant cluster together with its analysed vowel, shva and dagesh.
+    ///
+    /// A holam male or shureq is reported on the vav that carries the point,
+    /// the vowel itself is pronounced after the preceding consonant.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct HebrewClusterVowel<'a> {
+        pub cluster: HebrewCluster<'a>,
+        pub vowel: Option<HebrewVowel>,
+        pub shva: Option<ShvaKind>,
+        pub dagesh: Option<DageshKind>,
+        /// The cluster is the first consonant of a word
+        pub word_start: bool,
+        /// The cluster is the last consonant of a word
+        pub word_end: bool,
+    }
+
+    impl HebrewClusterVowel<'_> {
+        /// Checks if the cluster is a vav, yod, he or alef without points that only marks the preceding vowel.
+        pub fn is_mater_lectionis(&self, previous: Option<&HebrewClusterVowel<'_>>) -> bool {
+            if self.vowel.is_some() || self.shva.is_some() || self.dagesh.is_some() {
+                return false;
+            }
+            let quality = match previous.and_then(|p| p.vowel) {
+                Some(vowel) => vowel.quality,
+                None => return false,
+            };
+            match self.cluster.base() {
+                Some('ו') => matches!(quality, VowelQuality::O | VowelQuality::U),
+                Some('י') => matches!(quality, VowelQuality::I | VowelQuality::E),
+                Some('ה') => self.word_end,
+                Some('א') => true,
+                _ => false,
+            }
+        }
+    }
+
+    /// Get the vowel, shva and dagesh of every Hebrew consonant of the given string.
+    ///
+    /// Clusters that do not start with a Hebrew consonant are skipped.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::{get_hbr_vowels, DageshKind, ShvaKind, VowelKind, VowelQuality};
+    ///
+    /// let vowels = get_hbr_vowels("רוּחַ");
+    /// let shureq = vowels[1].vowel.unwrap();
+    /// assert_eq!(shureq.kind, VowelKind::Shureq);
+    /// let patah = vowels[2].vowel.unwrap();
+    /// assert_eq!(patah.quality, VowelQuality::A);
+    /// assert_eq!(patah.kind, VowelKind::FurtivePatah);
+    ///
+    /// let vowels = get_hbr_vowels("מִכְתָּב");
+    /// assert_eq!(vowels[1].shva, Some(ShvaKind::Silent));
+    /// assert_eq!(vowels[2].dagesh, Some(DageshKind::Lene));
+    /// ```
+    pub fn get_hbr_vowels(text: &str) -> Vec<HebrewClusterVowel<'_>> {
+        let clusters = get_hbr_clusters(text);
+        let mut result: Vec<HebrewClusterVowel<'_>> = Vec::new();
+        for (i, cluster) in clusters.iter().enumerate() {
+            let base = match cluster.base() {
+                Some(c) if c.is_hbr_consonant() => c,
+                _ => continue,
+            };
+            // a geresh (as in ג׳) does not end the word
+            let previous = match i.checked_sub(1).map(|p| &clusters[p]) {
+                Some(p) if p.base() == Some('\u{05F3}') => i.checked_sub(2).map(|p| &clusters[p]),
+                p => p,
+            };
+            let previous = previous.filter(|p| is_consonant(p));
+            let next = match clusters.get(i + 1) {
+                Some(n) if n.base() == Some('\u{05F3}') => clusters.get(i + 2),
+                n => n,
+            };
+            let next = next.filter(|n| is_consonant(n));
+            let vowel = analyse_vowel(base, cluster, previous, next);
+            result.push(HebrewClusterVowel {
+                cluster: cluster.clone(),
+                vowel,
+                shva: None,
+                dagesh: None,
+                word_start: previous.is_none(),
+                word_end: next.is_none(),
+            });
+        }
+        for i in 0..result.len() {
+            let dagesh = analyse_dagesh(&result, i);
+            result[i].dagesh = dagesh;
+            let shva = analyse_shva(&result, i);
+            result[i].shva = shva;
+        }
+        result
+    }
+
+    fn is_consonant(cluster: &HebrewCluster<'_>) -> bool {
+        cluster.base().map_or(false, |c| c.is_hbr_consonant())
+    }
+
+    fn analyse_dagesh(clusters: &[HebrewClusterVowel<'_>], i: usize) -> Option<DageshKind> {
+        let current = &clusters[i];
+        let is_shureq = current.vowel.map_or(false, |v| v.kind == VowelKind::Shureq);
+        if !current.cluster.has_mark('\u{05BC}') || is_shureq {
+            return None;
+        }
+        let base = current.cluster.base();
+        if base == Some('ה') && current.word_end {
+            return Some(DageshKind::Mappiq);
+        }
+        if !matches!(base, Some('ב' | 'ג' | 'ד' | 'כ' | 'ך' | 'פ' | 'ף' | 'ת')) {
+            return Some(DageshKind::Forte);
+        }
+        if current.word_start {
+            return Some(DageshKind::Lene);
+        }
+        // a begadkefat letter after a closed syllable
+        let previous = &clusters[i - 1];
+        let closed = previous.shva == Some(ShvaKind::Silent)
+            || (previous.vowel.is_none()
+                && previous.shva.is_none()
+                && !matches!(previous.cluster.base(), Some('א' | 'ה' | 'ו' | 'י')));
+        if closed {
+            Some(DageshKind::Lene)
+        } else {
+            Some(DageshKind::Forte)
+        }
+    }
+
+    fn analyse_shva(clusters: &[HebrewClusterVowel<'_>], i: usize) -> Option<ShvaKind> {
+        let current = &clusters[i];
+        if !current.cluster.has_mark('\u{05B0}') {
+            return None;
+        }
+        if current.word_end {
+            return Some(ShvaKind::Silent);
+        }
+        if current.word_start || current.dagesh == Some(DageshKind::Forte) {
+            return Some(ShvaKind::Vocal);
+        }
+        let previous = &clusters[i - 1];
+        if previous.shva.is_some() {
+            // the second of two shvas
+            return Some(ShvaKind::Vocal);
+        }
+        if clusters[i + 1].cluster.base() == current.cluster.base() {
+            return Some(ShvaKind::Vocal);
+        }
+        // after a long vowel, skipping a vowel letter
+        let syllable = if previous.vowel.is_none() && !previous.word_start {
+            &clusters[i - 2]
+        } else {
+            previous
+        };
+        if syllable
+            .vowel
+            .map_or(false, |v| v.length == VowelLength::Long)
+        {
+            Some(ShvaKind::Vocal)
+        } else {
+            Some(ShvaKind::Silent)
+        }
+    }
+
+    fn analyse_vowel(
+        base: char,
+        cluster: &HebrewCluster<'_>,
+        previous: Option<&HebrewCluster<'_>>,
+        next: Option<&HebrewCluster<'_>>,
+    ) -> Option<HebrewVowel> {
+        let point = cluster.marks().find(|&c| is_vowel_point(c));
+        let dagesh = cluster.has_mark('\u{05BC}');
+        let previous_vocalized = previous.map_or(false, |p| p.marks().any(is_vowel_or_shva));
+
+        let point = match point {
+            Some(point) => point,
+            // shureq: a vav with dagesh and no vowel of its own
+            None if base == 'ו' && dagesh && !previous_vocalized => {
+                return Some(vowel(
+                    '\u{05BC}',
+                    VowelQuality::U,
+                    VowelLength::Long,
+                    VowelKind::Shureq,
+                ));
+            }
+            None => return None,
+        };
+        let (quality, length) = match point {
+            '\u{05B1}' => (VowelQuality::E, VowelLength::Reduced),
+            '\u{05B2}' => (VowelQuality::A, VowelLength::Reduced),
+            '\u{05B3}' => (VowelQuality::O, VowelLength::Reduced),
+            '\u{05B4}' => (VowelQuality::I, VowelLength::Short),
+            '\u{05B5}' => (VowelQuality::E, VowelLength::Long),
+            '\u{05B6}' => (VowelQuality::E, VowelLength::Short),
+            '\u{05B7}' => (VowelQuality::A, VowelLength::Short),
+            '\u{05B8}' => (VowelQuality::A, VowelLength::Long),
+            '\u{05B9}' | '\u{05BA}' => (VowelQuality::O, VowelLength::Long),
+            '\u{05BB}' => (VowelQuality::U, VowelLength::Short),
+            _ => (VowelQuality::O, VowelLength::Short), // qamats qatan
+        };
+        let next_is_bare = |letter: char| {
+            next.map_or(false, |n| {
+                n.base() == Some(letter)
+                    && n.marks().all(|c| !is_vowel_or_shva(c) && c != '\u{05BC}')
+            })
+        };
+
+        let kind = match point {
+            '\u{05B1}'..='\u{05B3}' => VowelKind::CompoundShva,
+            '\u{05B7}' if next.is_none() && is_furtive_base(base, dagesh) => {
+                VowelKind::FurtivePatah
+            }
+            '\u{05BA}' => VowelKind::HolamHaser,
+            '\u{05B9}' if base == 'ו' && !previous_vocalized && previous.is_some() => {
+                VowelKind::HolamMale
+            }
+            '\u{05B9}' if base != 'ו' && next_is_bare('ו') => VowelKind::HolamMale,
+            '\u{05B9}' => VowelKind::HolamHaser,
+            '\u{05B4}' if next_is_bare('י') => VowelKind::HiriqMale,
+            _ => VowelKind::Simple,
+        };
+        let length = if kind == VowelKind::HiriqMale {
+            VowelLength::Long
+        } else {
+            length
+        };
+        Some(vowel(point, quality, length, kind))
+    }
+
+    fn vowel(
+        point: char,
+        quality: VowelQuality,
+        length: VowelLength,
+        kind: VowelKind,
+    ) -> HebrewVowel {
+        HebrewVowel {
+            point,
+            quality,
+            length,
+            kind,
+        }
+    }
+
+    /// Ḥet, ʿayin and he with mappiq take a furtive patah at the end of a word.
+    fn is_furtive_base(base: char, dagesh: bool) -> bool {
+        matches!(base, 'ח' | 'ע') || (base == 'ה' && dagesh)
+    }
+
+    /// Vowel points and hateph vowels, the simple shva excluded.
+    fn is_vowel_point(c: char) -> bool {
+        c.is_hbr_point_vowel() || matches!(c, '\u{05B1}'..='\u{05B3}')
+    }
+
+    fn is_vowel_or_shva(c: char) -> bool {
+        c.is_hbr_point_vowel() || c.is_hbr_point_semi_vowel()
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+
+    fn kinds(text: &str) -> Vec<Option<VowelKind>> {
+        get_hbr_vowels(text)
+            .iter()
+            .map(|v| v.vowel.map(|v| v.kind))
+            .collect()
+    }
+
+    #[test]
+    fn furtive_patah_under_final_het_ayin_and_mappiq_he() {
+        assert_eq!(kinds("רוּחַ")[2], Some(VowelKind::FurtivePatah));
+        assert_eq!(kinds("יָדֻעַ")[2], Some(VowelKind::FurtivePatah));
+        assert_eq!(kinds("גָּבֹהַּ")[2], Some(VowelKind::FurtivePatah));
+    }
+
+    #[test]
+    fn patah_not_word_final_is_simple() {
+        assert_eq!(kinds("חַג")[0], Some(VowelKind::Simple));
+        // he without mappiq
+        assert_eq!(kinds("לָהַ")[1], Some(VowelKind::Simple));
+    }
+
+    #[test]
+    fn hateph_vowels_are_compound_shvas() {
+        let vowels = get_hbr_vowels("אֲנִי אֱמֶת חֳלִי");
+        let hatephs: Vec<HebrewVowel> = vowels
+            .iter()
+            .filter_map(|v| v.vowel)
+            .filter(|v| v.kind == VowelKind::CompoundShva)
+            .collect();
+        assert_eq!(hatephs.len(), 3);
+        assert_eq!(hatephs[0].quality, VowelQuality::A);
+        assert_eq!(hatephs[1].quality, VowelQuality::E);
+        assert_eq!(hatephs[2].quality, VowelQuality::O);
+        assert!(hatephs.iter().all(|v| v.length == VowelLength::Reduced));
+    }
+
+    #[test]
+    fn holam_male_and_haser() {
+        // shalom: holam on the vav
+        assert_eq!(kinds("שָׁלוֹם")[2], Some(VowelKind::HolamMale));
+        // mitsvot: consonantal vav after a shva
+        assert_eq!(kinds("מִצְוֹת")[2], Some(VowelKind::HolamHaser));
+        // holam haser for vav
+        assert_eq!(kinds("מִצְוֺת")[2], Some(VowelKind::HolamHaser));
+        // moshe: holam on the consonant
+        assert_eq!(kinds("מֹשֶׁה")[0], Some(VowelKind::HolamHaser));
+    }
+
+    #[test]
+    fn shureq_and_hiriq_male() {
+        let vowels = get_hbr_vowels("שׁוּק דִּין");
+        assert_eq!(vowels[1].vowel.unwrap().kind, VowelKind::Shureq);
+        assert_eq!(vowels[1].vowel.unwrap().quality, VowelQuality::U);
+        let hiriq = vowels[3].vowel.unwrap();
+        assert_eq!(hiriq.kind, VowelKind::HiriqMale);
+        assert_eq!(hiriq.length, VowelLength::Long);
+        assert_eq!(vowels[4].vowel, None);
+    }
+
+    #[test]
+    fn shva_vocal_and_silent() {
+        let shvas: Vec<Option<ShvaKind>> = get_hbr_vowels("בְּנֵי יִשְׂרָאֵל כָּתְבוּ תִּשְׁמְרוּ")
+            .iter()
+            .map(|v| v.shva)
+            .filter(|s| s.is_some())
+            .collect();
+        assert_eq!(
+            shvas,
+            vec![
+                Some(ShvaKind::Vocal),  // word-initial
+                Some(ShvaKind::Silent), // after a short vowel
+                Some(ShvaKind::Vocal),  // after a long vowel
+                Some(ShvaKind::Silent), // first of two shvas
+                Some(ShvaKind::Vocal),  // second of two shvas
+            ]
+        );
+        assert_eq!(get_hbr_vowels("לֶךְ")[1].shva, Some(ShvaKind::Silent));
+    }
+
+    #[test]
+    fn dagesh_lene_forte_and_mappiq() {
+        let vowels = get_hbr_vowels("בַּיִת שַׁבָּת לָהּ");
+        assert_eq!(vowels[0].dagesh, Some(DageshKind::Lene));
+        assert_eq!(vowels[4].dagesh, Some(DageshKind::Forte));
+        assert_eq!(vowels[7].dagesh, Some(DageshKind::Mappiq));
+        assert_eq!(get_hbr_vowels("שׁוּק")[1].dagesh, None);
+    }
+
+    #[test]
+    fn non_hebrew_clusters_are_skipped() {
+        assert!(get_hbr_vowels("abc 123").is_empty());
+    }
+}
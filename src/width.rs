@@ -0,0 +1,119 @@
+pub mod unicode_block_hebrew {
+    use crate::width::char_width;
+    use std::borrow::Cow;
+
+    /// Get the number of terminal columns the given string occupies.
+    ///
+    /// Hebrew points, accents and marks, other combining characters and invisible formatting characters
+    /// take no column, presentation forms take one column and East Asian wide characters two.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::hbr_display_width;
+    ///
+    /// assert_eq!(hbr_display_width("בְּרֵאשִׁ֖ית"), 6);
+    /// assert_eq!(hbr_display_width("\u{FB2C}\u{FB4F} abc"), 6);
+    /// ```
+    pub fn hbr_display_width(text: &str) -> usize {
+        text.chars().map(char_width).sum()
+    }
+
+    /// Truncates the given string to at most `max_cols` columns, without separating a consonant from its marks.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::truncate_hbr;
+    ///
+    /// assert_eq!(truncate_hbr("שָׁלוֹם עוֹלָם", 3).as_ref(), "שָׁלוֹ");
+    /// assert_eq!(truncate_hbr("שָׁלוֹם", 10).as_ref(), "שָׁלוֹם");
+    /// ```
+    pub fn truncate_hbr(text: &str, max_cols: usize) -> Cow<'_, str> {
+        let mut cols = 0;
+        for (pos, c) in text.char_indices() {
+            cols += char_width(c);
+            if cols > max_cols {
+                return Cow::Borrowed(&text[..pos]);
+            }
+        }
+        Cow::Borrowed(text)
+    }
+
+    /// Pads the given string with spaces at its end until it occupies `cols` columns.
+    ///
+    /// A string that is already wide enough is returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use hebrew_unicode_utils::pad_hbr;
+    ///
+    /// assert_eq!(pad_hbr("שָׁלוֹם", 6).as_ref(), "שָׁלוֹם  ");
+    /// assert_eq!(pad_hbr("שָׁלוֹם", 2).as_ref(), "שָׁלוֹם");
+    /// ```
+    pub fn pad_hbr(text: &str, cols: usize) -> Cow<'_, str> {
+        let width = hbr_display_width(text);
+        if width >= cols {
+            return Cow::Borrowed(text);
+        }
+        let mut padded = String::with_capacity(text.len() + cols - width);
+        padded.push_str(text);
+        padded.extend(std::iter::repeat(' ').take(cols - width));
+        Cow::Owned(padded)
+    }
+}
+
+use crate::bidi::is_bidi_control;
+use crate::clusters::is_hbr_combining;
+
+/// Returns the number of terminal columns of a character.
+pub(crate) fn char_width(c: char) -> usize {
+    match c {
+        _ if is_hbr_combining(c) || is_bidi_control(c) => 0,
+        '\u{0000}'..='\u{001F}' | '\u{007F}'..='\u{009F}' => 0,
+        '\u{0300}'..='\u{036F}' | '\u{1AB0}'..='\u{1AFF}' | '\u{20D0}'..='\u{20FF}' => 0,
+        '\u{200B}'..='\u{200D}' | '\u{2060}'..='\u{2064}' | '\u{FE00}'..='\u{FE0F}' => 0,
+        '\u{FE20}'..='\u{FE2F}' | '\u{FEFF}' => 0,
+        '\u{1100}'..='\u{115F}' | '\u{2E80}'..='\u{303E}' | '\u{3041}'..='\u{33FF}' => 2,
+        '\u{3400}'..='\u{4DBF}' | '\u{4E00}'..='\u{9FFF}' | '\u{A000}'..='\u{A4CF}' => 2,
+        '\u{AC00}'..='\u{D7A3}' | '\u{F900}'..='\u{FAFF}' | '\u{FE30}'..='\u{FE4F}' => 2,
+        '\u{FF00}'..='\u{FF60}' | '\u{FFE0}'..='\u{FFE6}' => 2,
+        '\u{1F300}'..='\u{1F64F}' | '\u{1F900}'..='\u{1F9FF}' => 2,
+        '\u{20000}'..='\u{3FFFD}' => 2,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod unit_test {
+    use crate::*;
+
+    #[test]
+    fn display_width_ignores_marks_and_controls() {
+        let test_str = "וַיְהִי־עֶ֖רֶב\u{200F} 12";
+        assert_eq!(hbr_display_width(test_str), 11);
+        assert_eq!(hbr_display_width("ה֯"), 1);
+        assert_eq!(hbr_display_width("日本"), 4);
+        assert_eq!(hbr_display_width(""), 0);
+    }
+
+    #[test]
+    fn truncate_keeps_marks_with_consonant() {
+        let test_str = "בְּרֵאשִׁ֖ית";
+        for cols in 0..=6 {
+            let truncated = truncate_hbr(test_str, cols);
+            assert_eq!(hbr_display_width(&truncated), cols);
+            assert!(test_str[truncated.len()..]
+                .chars()
+                .next()
+                .map_or(true, |c| hbr_display_width(c.encode_utf8(&mut [0; 4])) == 1));
+        }
+        assert_eq!(truncate_hbr("日本", 3).as_ref(), "日");
+    }
+
+    #[test]
+    fn pad_aligns_columns() {
+        let rows = ["שָׁלוֹם", "abc", "\u{FB2C}"];
+        for row in rows.iter() {
+            assert_eq!(hbr_display_width(&pad_hbr(row, 8)), 8);
+        }
+    }
+}